@@ -1,208 +1,947 @@
 //! This a module for setting up a game of Tic-Tac-Toe.
 //!
-//! It contains an aliased type for the game board, an enum for the game turn, and a struct for the
-//! game itself.
+//! It contains an aliased type for the game board, an enum for the game turn, an enum for the
+//! outcome of a position, an enum for the difficulty, an enum for the direction of a winning
+//! line, an enum for the rendering of open cells, an enum for the color theme, an enum for the
+//! assignment of tokens, a struct for the session scoreboard, a struct for the rendering options,
+//! a struct for the directions of the winning lines, a struct for the active configuration, a
+//! struct for the bot's decisions, an enum for the events sent to spectators, a struct for custom
+//! move validation, a struct for the rules engine, and a struct for the game itself.
 use rand;
+use rand::{Rng, SeedableRng, StdRng};
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::fs;
+use std::fs::OpenOptions;
+use std::hash::{Hash, Hasher};
 use std::io;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use terminal;
+use terminal::Input;
+
+/// The smallest supported board size and win length.
+const MIN_SIZE: usize = 3;
+
+/// The largest supported board size, which keeps the board and its lines small enough to
+/// allocate, and the number of cells within a move.
+const MAX_SIZE: usize = 100;
+
+/// The largest number of open cells the hard bot searches with minimax, before it falls back to
+/// the medium bot's heuristics.
+const MAX_SEARCH_CELLS: usize = 9;
+
+/// The largest number of open cells the adaptive bot plays the endgame at, where it searches
+/// with minimax.
+const ENDGAME_CELLS: usize = 4;
+
+/// The magic number at the start of a binary save.
+const BINARY_SAVE_MAGIC: &[u8; 4] = b"TTTB";
+
+/// The version of the binary save format, bumped whenever the layout changes.
+const BINARY_SAVE_VERSION: u8 = 2;
+
+/// The content of a cell that holds an obstacle, which is never played and never part of a line.
+const OBSTACLE: &str = "#";
+
+/// The named openings of the default board, by their moves. A position matches an opening up to
+/// the rotations and reflections of the board.
+const OPENING_BOOK: &[(&[u32], &str)] = &[
+    (&[1], "Corner Game"),
+    (&[2], "Edge Game"),
+    (&[5], "Center Game"),
+    (&[1, 5], "Corner Game, Center Reply"),
+    (&[1, 9], "Corner Game, Opposite Corner Reply"),
+    (&[1, 3], "Corner Game, Adjacent Corner Reply"),
+    (&[1, 2], "Corner Game, Adjacent Edge Reply"),
+    (&[1, 6], "Corner Game, Far Edge Reply"),
+    (&[2, 5], "Edge Game, Center Reply"),
+    (&[2, 8], "Edge Game, Opposite Edge Reply"),
+    (&[5, 1], "Center Game, Corner Reply"),
+    (&[5, 2], "Center Game, Edge Reply"),
+];
+
+/// The width and height of a cell in a PPM image of the board, in pixels.
+const PPM_CELL_SIZE: usize = 32;
+
+/// The width of the grid lines in a PPM image of the board, in pixels.
+const PPM_LINE_WIDTH: usize = 2;
 
 /// The game board as an aliased type.
-type Board = Vec<Vec<String>>;
+pub type Board = Vec<Vec<String>>;
+
+/// The legal moves ranked from best to worst, with the outcome of each move and the number of
+/// plies it takes to reach it, as an aliased type.
+type RankedMoves = Vec<(u32, Outcome, u32)>;
 
 /// A turn in the game as an Enum.
-#[derive(Debug, PartialEq)]
-enum Turn {
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub enum Turn {
     /// The player's turn.
     Player,
     /// The bot's turn.
     Bot,
 }
 
-/// The game represented as a struct.
-#[derive(Debug)]
-pub struct Game {
+/// The game-theoretic outcome of a position as an Enum, from the perspective of one side.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Outcome {
+    /// The side can force a win.
+    Win,
+    /// Neither side can force a win.
+    Draw,
+    /// The other side can force a win.
+    Loss,
+}
+
+impl Outcome {
+    /// Gets the same outcome from the perspective of the other side.
+    fn flip(self) -> Outcome {
+        match self {
+            Outcome::Win => Outcome::Loss,
+            Outcome::Draw => Outcome::Draw,
+            Outcome::Loss => Outcome::Win,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Outcome::Win => write!(f, "win"),
+            Outcome::Draw => write!(f, "draw"),
+            Outcome::Loss => write!(f, "loss"),
+        }
+    }
+}
+
+/// A difficulty level as an Enum, for the bot and for puzzles.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Difficulty {
+    /// The easiest level, where the bot plays randomly.
+    Easy,
+    /// The intermediate level, where the bot takes wins and blocks threats.
+    Medium,
+    /// The hardest level, where the bot searches with minimax.
+    Hard,
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(difficulty: &str) -> Result<Difficulty, String> {
+        match difficulty.trim().to_lowercase().as_str() {
+            "easy" => Ok(Difficulty::Easy),
+            "medium" => Ok(Difficulty::Medium),
+            "hard" => Ok(Difficulty::Hard),
+            _ => Err(String::from("Please input easy, medium, or hard!")),
+        }
+    }
+}
+
+impl Difficulty {
+    /// Gets the number of plies the quickest forced win of a puzzle at this difficulty takes.
+    fn plies_to_win(self) -> u32 {
+        match self {
+            Difficulty::Easy => 1,
+            Difficulty::Medium => 3,
+            Difficulty::Hard => 5,
+        }
+    }
+}
+
+/// A preference as an Enum, for choosing among equally good moves.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TieBreak {
+    /// Choose the first move in the order searched.
+    First,
+    /// Choose a random move, with the game's random number generator.
+    Random,
+    /// Choose the move closest to the center of the board.
+    Center,
+    /// Choose a corner move, if there is one.
+    Corner,
+    /// Choose the move that sets the most traps, i.e. leaves the opponent the most replies that
+    /// lose against best play.
+    Traps,
+}
+
+/// The direction of a winning line as an Enum, e.g. for drawing a strike-through.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    /// Along a row, by its index from the top, starting at 0.
+    Row(usize),
+    /// Along a column, by its index from the left, starting at 0.
+    Col(usize),
+    /// Down and to the right, parallel to the main diagonal.
+    DiagMain,
+    /// Down and to the left, parallel to the anti-diagonal.
+    DiagAnti,
+}
+
+/// A way of rendering the open cells of the board as an Enum.
+///
+/// Whatever is rendered, moves are still entered by the number of the cell.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EmptyStyle {
+    /// Render an open cell as its move number.
+    Number,
+    /// Render an open cell as a space.
+    Blank,
+    /// Render an open cell as a ".".
+    Dot,
+}
+
+/// The color theme of the rendered board as an Enum.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Theme {
+    /// Red X, blue O, dim borders, and yellow markers.
+    Default,
+    /// Bold bright colors, for terminals and eyes where the default colors are hard to tell apart.
+    HighContrast,
+    /// No colors at all, e.g. for accessibility or piping the output.
+    Monochrome,
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(theme: &str) -> Result<Theme, String> {
+        match theme.trim().to_lowercase().as_str() {
+            "default" => Ok(Theme::Default),
+            "high-contrast" => Ok(Theme::HighContrast),
+            "monochrome" => Ok(Theme::Monochrome),
+            _ => Err(String::from(
+                "Please input default, high-contrast, or monochrome!",
+            )),
+        }
+    }
+}
+
+impl Theme {
+    /// Gets the ANSI color code of a part of the board, if the theme colors it.
+    ///
+    /// # Arguments
+    ///
+    /// * `part` - The part of the board.
+    fn code(self, part: ThemePart) -> Option<&'static str> {
+        match (self, part) {
+            (Theme::Monochrome, _) => None,
+            (Theme::Default, ThemePart::X) => Some("\x1b[31m"),
+            (Theme::Default, ThemePart::O) => Some("\x1b[34m"),
+            (Theme::Default, ThemePart::Border) => Some("\x1b[90m"),
+            (Theme::Default, ThemePart::Marker) => Some("\x1b[33m"),
+            (Theme::HighContrast, ThemePart::X) => Some("\x1b[1;91m"),
+            (Theme::HighContrast, ThemePart::O) => Some("\x1b[1;96m"),
+            (Theme::HighContrast, ThemePart::Border) => Some("\x1b[97m"),
+            (Theme::HighContrast, ThemePart::Marker) => Some("\x1b[1;93m"),
+        }
+    }
+
+    /// Colors text as a part of the board, leaving it as is if the theme does not color it.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to color.
+    /// * `part` - The part of the board the text is.
+    fn paint(self, text: &str, part: ThemePart) -> String {
+        match self.code(part) {
+            Some(code) => format!("{}{}\x1b[0m", code, text),
+            None => text.to_string(),
+        }
+    }
+}
+
+/// A part of the rendered board that a theme colors as an Enum.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ThemePart {
+    /// The X tokens.
+    X,
+    /// The O tokens.
+    O,
+    /// The borders between the cells.
+    Border,
+    /// The markers of the last move and the legal moves.
+    Marker,
+}
+
+/// A rule for assigning the tokens to the sides as an Enum.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TokenRule {
+    /// The player always plays X and the bot O, whoever moves first.
+    FixedSide,
+    /// Whoever moves first in a game plays X.
+    FirstMoverIsX,
+}
+
+/// The scoreboard of a session as a struct, from the player's perspective.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Scoreboard {
+    /// The number of rounds won by the player.
+    pub wins: u32,
+    /// The number of rounds lost by the player.
+    pub losses: u32,
+    /// The number of drawn rounds.
+    pub draws: u32,
+}
+
+/// The spacing of the rendered board as a struct, which defaults to the compact board.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderOptions {
+    /// The number of spaces on each side of a cell's content. The last move and legal move
+    /// markers take the outermost space, so they are hidden with no padding.
+    pub padding: usize,
+    /// The number of blank lines above and below the content of each row.
+    pub row_spacing: usize,
+}
+
+impl Default for RenderOptions {
+    fn default() -> RenderOptions {
+        RenderOptions {
+            padding: 1,
+            row_spacing: 0,
+        }
+    }
+}
+
+/// The directions of the lines that win the game as a struct, which defaults to every direction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WinDirections {
+    /// Whether a row wins.
+    pub rows: bool,
+    /// Whether a column wins.
+    pub cols: bool,
+    /// Whether a diagonal wins, in either direction.
+    pub diagonals: bool,
+}
+
+impl Default for WinDirections {
+    fn default() -> WinDirections {
+        WinDirections {
+            rows: true,
+            cols: true,
+            diagonals: true,
+        }
+    }
+}
+
+/// The active configuration of a game as a struct, e.g. to print in a bug report.
+///
+/// Every option set by the builder methods is included, except for the event sender, the
+/// commentator, and the move validator, which are channels and closures rather than settings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// The number of rows of the board.
+    pub rows: usize,
+    /// The number of columns of the board.
+    pub cols: usize,
+    /// The number of tokens in a row needed to win.
+    pub win_length: usize,
+    /// Whether the lines wrap around the edges of the board.
+    pub toroidal: bool,
+    /// The directions of the lines that win the game.
+    pub win_directions: WinDirections,
+    /// The cells that hold obstacles in every game.
+    pub obstacles: Vec<u32>,
+    /// The cell of the player's handicap stone, if there is one.
+    pub handicap_stone: Option<u32>,
+    /// Whether a move may overwrite a taken cell, which is only possible in debug builds.
+    pub allow_overwrite: bool,
+    /// The difficulty of the bot.
+    pub difficulty: Difficulty,
+    /// Whether the difficulty of the bot ramps up as the board fills.
+    pub adaptive_difficulty: bool,
+    /// The preference for choosing among equally good moves of the bot.
+    pub tie_break: TieBreak,
+    /// The rule for assigning the tokens to the sides.
+    pub token_rule: TokenRule,
+    /// The seed of the random number generator, if it was seeded explicitly.
+    pub seed: Option<u64>,
+    /// The time the hard bot may search for a move, if it is limited.
+    pub search_budget: Option<Duration>,
+    /// The time the player and the bot each have to make a move, if the moves are timed.
+    pub time_controls: Option<(Duration, Duration)>,
+    /// The number of rounds the session ends after, if there is a limit.
+    pub round_limit: Option<u32>,
+    /// Whether two players play against each other instead of against the bot.
+    pub two_player: bool,
+    /// Whether the fog of war hides the bot's most recent move.
+    pub fog_of_war: bool,
+    /// Whether the bot mirrors the player's last move.
+    pub copycat: bool,
+    /// Whether the bot only plays moves that keep the game drawn.
+    pub teaching: bool,
+    /// Whether the bot taunts the player with its evaluation of each move.
+    pub challenge: bool,
+    /// Whether the player is offered to resign once their position is lost.
+    pub mercy_rule: bool,
+    /// Whether the player confirms each move before it is placed.
+    pub confirm_moves: bool,
+    /// Whether the bot avoids the center when it opens on an empty board.
+    pub bot_avoid_center_opening: bool,
+    /// The moves the bot is scripted to play, in order.
+    pub bot_script: Vec<u32>,
+    /// Whether the ranked moves collapse the moves leading to symmetric positions.
+    pub collapse_symmetric: bool,
+    /// Whether the bot announces when it starts thinking and how long its move took.
+    pub think_out_loud: bool,
+    /// Whether the bot explains why it chose each move.
+    pub explain_moves: bool,
+    /// Whether the next round starts right after a result, without asking to play again.
+    pub auto_continue: bool,
+    /// Whether the final board of a round is held until the player presses enter.
+    pub hold_result: bool,
+    /// Whether the session summary shows how fast the human players moved.
+    pub speed_leaderboard: bool,
+    /// Whether the move prompt reminds the player of the commands they can type.
+    pub show_commands_hint: bool,
+    /// The color theme of the rendered board.
+    pub theme: Theme,
+    /// The spacing of the rendered board.
+    pub render_options: RenderOptions,
+    /// How the open cells are rendered.
+    pub show_empty_as: EmptyStyle,
+    /// Whether the most recently played token is marked.
+    pub mark_last_move: bool,
+    /// Whether the still open cells are marked.
+    pub highlight_legal: bool,
+    /// Whether a reference grid of the move numbers is printed beneath the board.
+    pub show_move_numbers_legend: bool,
+    /// Where the in-progress game is saved when the session is interrupted, if anywhere.
+    pub interrupt_save_path: Option<PathBuf>,
+    /// Where the game is saved after every move, if anywhere.
+    pub autosave_path: Option<PathBuf>,
+    /// Where a line is appended for every completed game, if anywhere.
+    pub logfile_path: Option<PathBuf>,
+    /// Where the scoreboard is kept between sessions, if anywhere.
+    pub score_path: Option<PathBuf>,
+}
+
+/// The decision behind a move of the bot as a struct, e.g. for a debug overlay.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Decision {
+    /// The legal moves the bot considered, in ascending order, with their outcomes for the bot
+    /// by minimax, or `None` if too many cells were open to search.
+    pub candidates: Vec<(u32, Option<Outcome>)>,
+    /// The move the bot chose.
+    pub chosen: u32,
+    /// The reason the bot chose the move, e.g. "blocking your threat at 3".
+    pub reason: String,
+}
+
+/// An event of a session as an Enum, sent to spectators as it occurs.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GameEvent {
+    /// A side played a move.
+    MovePlayed(Turn, u32),
+    /// A side won the round.
+    RoundWon(Turn),
+    /// The round was drawn.
+    Draw,
+    /// The scoreboard changed at the end of a round, to its new value.
+    ScoreUpdated(Scoreboard),
+}
+
+impl fmt::Display for Scoreboard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Wins: {}, Losses: {}, Draws: {}",
+            self.wins, self.losses, self.draws
+        )
+    }
+}
+
+/// A custom check of the player's moves as a struct, run after the built-in validation.
+///
+/// The check is shared with `Arc`, so a `Game` stays `Clone` and `Send`.
+#[derive(Clone)]
+struct MoveValidator(Arc<ValidateMove>);
+
+/// The custom check of a move, given the move and the game before it, as an aliased type.
+type ValidateMove = dyn Fn(u32, &Game) -> Result<(), String> + Send + Sync;
+
+impl fmt::Debug for MoveValidator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MoveValidator")
+    }
+}
+
+/// A narrator of the moves as a struct, called after every move, e.g. for streaming or
+/// accessibility.
+///
+/// The narrator is shared with `Arc`, so a `Game` stays `Clone` and `Send`, and a clone narrates
+/// with the same narrator.
+#[derive(Clone)]
+struct Commentator(Arc<Mutex<Comment>>);
+
+/// The narration of a move, given the game after the move, the side that moved, and the move,
+/// as an aliased type.
+type Comment = dyn FnMut(&Game, Turn, u32) + Send;
+
+impl fmt::Debug for Commentator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Commentator")
+    }
+}
+
+/// The state of a cell of the board as an Enum.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CellState {
+    /// The cell is open, holding its move number.
+    Open,
+    /// The cell holds an X.
+    X,
+    /// The cell holds an O.
+    O,
+    /// The cell holds an obstacle, so it is never played.
+    Obstacle,
+}
+
+/// The end of a turn of the game as an Enum.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TurnEnd {
+    /// A move was played.
+    Moved,
+    /// The players agreed to a draw instead of a move.
+    DrawAgreed,
+    /// The player resigned a lost position instead of a move.
+    Resigned,
+    /// The side to move ran out of time, and forfeits the round.
+    TimedOut,
+}
+
+/// The reason the bot chose a move as an Enum.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MoveReason {
+    /// The move was the next of the bot's script.
+    Scripted,
+    /// The move mirrored the player's last move.
+    Mirrored,
+    /// The move won the game.
+    Win,
+    /// The move blocked the player's win.
+    Block,
+    /// The move was random.
+    Random,
+    /// The move was the best found by minimax.
+    Search,
+    /// The move took the center, preferred by the tie-break among the best moves by minimax.
+    Center,
+    /// The move kept the game drawn, for the teaching bot.
+    Teaching,
+}
+
+/// The rules and position of a game as a struct, without any input or output.
+///
+/// An `Engine` holds the board, the turn, and the options that decide when a game is won, so it
+/// can be searched, tested, and benchmarked on its own. A `Game` wraps it with the session.
+#[derive(Clone, Debug)]
+pub struct Engine {
     /// The game board.
     board: Board,
     /// The current turn of the game.
     current_turn: Turn,
+    /// The number of tokens in a row needed to win.
+    win_length: usize,
+    /// Whether the lines wrap around the edges of the board.
+    toroidal: bool,
+    /// The directions of the lines that win the game.
+    win_directions: WinDirections,
+    /// The lines of board locations that win the game, kept up to date with the board options.
+    win_lines: Vec<Vec<(usize, usize)>>,
+    /// The side that plays X, while the other side plays O.
+    x_side: Turn,
+    /// Whether a move may overwrite a taken cell, only ever on in debug builds.
+    allow_overwrite: bool,
 }
 
-impl Game {
-    /// Constructs a `Game` object.
-    ///
-    /// The board will default to a vector of chars indicating the available moves, and the first
-    /// turn will default to the player. For fun, a user could randomize the starting player.
+impl Engine {
+    /// Constructs an `Engine` object for the default 3x3 board, with the player to move.
     ///
     /// # Example
     ///
     /// ```
-    /// use game::Game;
+    /// use tic_tac_toe::game::Engine;
     ///
-    /// let game = Game::new();
+    /// let engine = Engine::new();
     /// ```
-    pub fn new() -> Game {
-        Game {
-            board: vec![
-                vec![String::from("1"), String::from("2"), String::from("3")],
-                vec![String::from("4"), String::from("5"), String::from("6")],
-                vec![String::from("7"), String::from("8"), String::from("9")],
-            ],
+    pub fn new() -> Engine {
+        let mut engine = Engine {
+            board: Engine::empty_board(3, 3),
             current_turn: Turn::Player,
-        }
+            win_length: 3,
+            toroidal: false,
+            win_directions: WinDirections::default(),
+            win_lines: Vec::new(),
+            x_side: Turn::Player,
+            allow_overwrite: false,
+        };
+
+        engine.win_lines = engine.lines();
+        engine
     }
 
-    /// Plays the game.
+    /// Applies a move for the current turn, then passes the turn to the other side.
+    ///
+    /// # Arguments
     ///
-    /// The starting turn alternates after each play.
+    /// * `game_move` - The move to apply, an integer between 1 and the number of cells.
     ///
     /// # Example
     ///
     /// ```
-    /// use game::Game;
+    /// use tic_tac_toe::game::Engine;
     ///
-    /// let mut game = Game::new();
+    /// let mut engine = Engine::new();
     ///
-    /// game.play_game();
+    /// assert!(engine.apply(5).is_ok());
+    /// assert!(engine.apply(5).is_err());
     /// ```
-    pub fn play_game(&mut self) {
-        let mut finished = false;
+    pub fn apply(&mut self, game_move: u32) -> Result<(), String> {
+        self.check_move(game_move)?;
+        self.make_move(game_move);
 
-        while !finished {
-            self.play_turn();
+        Ok(())
+    }
+
+    /// Gets the side that completed a line, if the game is won.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Engine, Turn};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// for &game_move in &[1, 4, 2, 5, 3] {
+    ///     engine.apply(game_move).unwrap();
+    /// }
+    ///
+    /// assert_eq!(engine.winner(), Some(Turn::Player));
+    /// ```
+    pub fn winner(&self) -> Option<Turn> {
+        [Turn::Player, Turn::Bot].iter().cloned().find(|&turn| {
+            let token = self.turn_token(&turn);
 
-            if self.game_is_won() {
-                self.print_board();
+            self.win_lines
+                .iter()
+                .any(|line| line.iter().all(|&(row, col)| self.board[row][col] == token))
+        })
+    }
 
-                match self.current_turn {
-                    Turn::Player => println!("You won!"),
-                    Turn::Bot => println!("You lost!"),
-                };
+    /// Gets the direction of the line that won the game, if the game is won.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Direction, Engine};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// for &game_move in &[1, 4, 2, 5, 3] {
+    ///     engine.apply(game_move).unwrap();
+    /// }
+    ///
+    /// assert_eq!(engine.winning_direction(), Some(Direction::Row(0)));
+    /// ```
+    pub fn winning_direction(&self) -> Option<Direction> {
+        let token = self.turn_token(&self.winner()?);
+        let line = self
+            .win_lines
+            .iter()
+            .find(|line| line.iter().all(|&(row, col)| self.board[row][col] == token))?;
 
-                self.reset();
+        Some(self.line_direction(line))
+    }
 
-                finished = Self::player_is_finished();
-            }
+    /// Gets every line completed by the winner, e.g. to highlight all of them when one move
+    /// completes several lines at once. Empty if the game is not won.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Engine;
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// for &game_move in &[1, 4, 2, 5, 3] {
+    ///     engine.apply(game_move).unwrap();
+    /// }
+    ///
+    /// assert_eq!(engine.winning_lines_all(), vec![vec![(0, 0), (0, 1), (0, 2)]]);
+    /// ```
+    pub fn winning_lines_all(&self) -> Vec<Vec<(usize, usize)>> {
+        let token = match self.winner() {
+            Some(winner) => self.turn_token(&winner),
+            None => return Vec::new(),
+        };
 
-            self.current_turn = self.get_next_turn();
-        }
+        self.win_lines
+            .iter()
+            .filter(|line| line.iter().all(|&(row, col)| self.board[row][col] == token))
+            .cloned()
+            .collect()
     }
 
-    /// Plays a turn of the game, getting moves from the player or bot.
-    fn play_turn(&mut self) {
-        self.print_board();
+    /// Gets each winning line with a label and the contents of its cells, i.e. a token or the
+    /// move number of an open cell, e.g. to see why a win did or didn't happen.
+    ///
+    /// A line is labeled by its direction, e.g. "row 0", "col 2", "diag main", or "diag anti". A
+    /// line shorter than the board, or a wrapped line on a toroidal board, is also labeled by its
+    /// first cell, e.g. "row 0 from (0, 1)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Engine;
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.apply(2).unwrap();
+    ///
+    /// let report = engine.line_report();
+    ///
+    /// assert_eq!(report.len(), 8);
+    /// assert_eq!(report[0], (String::from("row 0"), vec![
+    ///     String::from("1"),
+    ///     String::from("X"),
+    ///     String::from("3"),
+    /// ]));
+    /// ```
+    pub fn line_report(&self) -> Vec<(String, Vec<String>)> {
+        let (rows, cols) = (self.rows(), self.cols());
+
+        self.win_lines
+            .iter()
+            .map(|line| {
+                let (first_row, first_col) = line[0];
+                let (mut label, spans_board) = match self.line_direction(line) {
+                    Direction::Row(row) => {
+                        (format!("row {}", row), first_col == 0 && line.len() == cols)
+                    }
+                    Direction::Col(col) => {
+                        (format!("col {}", col), first_row == 0 && line.len() == rows)
+                    }
+                    Direction::DiagMain => (
+                        String::from("diag main"),
+                        line[0] == (0, 0) && line.len() == rows && rows == cols,
+                    ),
+                    Direction::DiagAnti => (
+                        String::from("diag anti"),
+                        line[0] == (0, cols - 1) && line.len() == rows && rows == cols,
+                    ),
+                };
 
-        let (valid_token, valid_move) = match self.current_turn {
-            Turn::Player => (String::from("X"), self.get_player_move()),
-            Turn::Bot => (String::from("O"), self.get_bot_move()),
-        };
+                if !spans_board {
+                    label = format!("{} from ({}, {})", label, first_row, first_col);
+                }
 
-        let (row, col) = Self::move_to_board_location(valid_move);
+                let contents = line
+                    .iter()
+                    .map(|&(row, col)| self.board[row][col].clone())
+                    .collect();
 
-        self.board[row][col] = valid_token;
+                (label, contents)
+            })
+            .collect()
     }
 
-    /// Prints the game board
+    /// Gets the direction of a line of board locations, by its first two locations.
     ///
-    /// Prints as:
-    /// +---+---+---+
-    /// | 1 | 2 | 3 |
-    /// +---+---+---+
-    /// | 4 | 5 | 6 |
-    /// +---+---+---+
-    /// | 7 | 8 | 9 |
-    /// +---+---+---+
-    fn print_board(&self) {
-        let seperator = "+---+---+---+";
-
-        println!("\n{}", seperator);
+    /// # Arguments
+    ///
+    /// * `line` - The line to get the direction of.
+    fn line_direction(&self, line: &[(usize, usize)]) -> Direction {
+        let (first_row, first_col) = line[0];
+        let (second_row, second_col) = line[1];
 
-        for row in &self.board {
-            println!("| {} |\n{}", row.join(" | "), seperator);
+        if first_row == second_row {
+            Direction::Row(first_row)
+        } else if first_col == second_col {
+            Direction::Col(first_col)
+        } else if second_col == (first_col + 1) % self.cols() {
+            Direction::DiagMain
+        } else {
+            Direction::DiagAnti
         }
-
-        print!("\n");
     }
 
-    /// Gets move from player.
-    fn get_player_move(&self) -> u32 {
-        loop {
-            let mut player_input = String::new();
+    /// Determines if the game is a dead draw, i.e. every line is blocked by both sides, so neither
+    /// side can win anymore. This is much cheaper than a search, but misses the draws that still
+    /// have an open line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Engine;
+    ///
+    /// assert!(!Engine::new().is_dead_draw());
+    /// ```
+    pub fn is_dead_draw(&self) -> bool {
+        let (player_token, bot_token) =
+            (self.turn_token(&Turn::Player), self.turn_token(&Turn::Bot));
 
-            println!("\nPlease enter your move (an integer between 1 and 9): ");
+        self.win_lines.iter().all(|line| {
+            let has_token = |token| line.iter().any(|&(row, col)| self.board[row][col] == token);
 
-            match io::stdin().read_line(&mut player_input) {
-                Err(_) => println!("Error reading input, try again!"),
-                Ok(_) => match self.validate_player_input(&player_input) {
-                    Err(err) => println!("{}", err),
-                    Ok(num) => return num,
-                },
-            }
-        }
+            has_token(player_token) && has_token(bot_token)
+        })
     }
 
-    /// Validates player input.
+    /// Determines if the game is over, either by a win or by a full board.
     ///
-    /// # Arguments
+    /// # Example
     ///
-    /// * `player_input` - The input take from the player.
-    fn validate_player_input(&self, player_input: &str) -> Result<u32, String> {
-        match player_input.trim().parse::<u32>() {
-            Err(_) => Err(String::from("Please input a valid unsigned integer!")),
-            Ok(number) => {
-                if self.is_valid_move(number) {
-                    Ok(number)
-                } else {
-                    Err(String::from(
-                        "Please input a number, between 1 and 9, not already chosen!",
-                    ))
-                }
-            }
-        }
+    /// ```
+    /// use tic_tac_toe::game::Engine;
+    ///
+    /// assert!(!Engine::new().is_over());
+    /// ```
+    pub fn is_over(&self) -> bool {
+        self.game_is_won() || self.is_full()
+    }
+
+    /// Gets the moves that are still open on the board, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Engine;
+    ///
+    /// assert_eq!(Engine::new().available_moves().len(), 9);
+    /// ```
+    pub fn available_moves(&self) -> Vec<u32> {
+        (1..=self.cell_count())
+            .filter(|game_move| self.is_open_move(*game_move))
+            .collect()
     }
 
-    /// Gets move from bot.
-    fn get_bot_move(&self) -> u32 {
-        let mut bot_move: u32 = rand::random::<u32>() % 9 + 1;
+    /// Checks that a move can be applied, i.e. the game is not over and the move is open.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_move` - The move to check.
+    fn check_move(&self, game_move: u32) -> Result<(), String> {
+        self.assert_playable()?;
 
-        while !self.is_valid_move(bot_move) {
-            bot_move = rand::random::<u32>() % 9 + 1;
+        if !self.is_valid_move(game_move) {
+            return Err(self.invalid_move_message());
         }
 
-        println!("Bot played moved at: {}", bot_move);
+        Ok(())
+    }
 
-        bot_move
+    /// Gets the message for a move that is out of range or already chosen.
+    fn invalid_move_message(&self) -> String {
+        format!(
+            "Please input a number, between 1 and {}, not already chosen!",
+            self.cell_count()
+        )
+    }
+
+    /// Gets the number of cells on the board, which is also the largest move.
+    fn cell_count(&self) -> u32 {
+        (self.rows() * self.cols()) as u32
     }
 
     /// Determins if move is valid.
     ///
+    /// A move is valid if it is open, or, when overwriting is allowed, if it is on the board.
+    ///
     /// # Arguments
     ///
     /// * `unchecked_move` - A move that is yet to be validated.
     fn is_valid_move(&self, unchecked_move: u32) -> bool {
+        if self.allow_overwrite && unchecked_move >= 1 && unchecked_move <= self.cell_count() {
+            let (row, col) = self.move_to_board_location(unchecked_move);
+
+            return self.cell_state(row, col) != CellState::Obstacle;
+        }
+
+        self.is_open_move(unchecked_move)
+    }
+
+    /// Determines if a move is on the board and its cell is neither taken nor an obstacle.
+    ///
+    /// # Arguments
+    ///
+    /// * `unchecked_move` - A move that is yet to be checked.
+    fn is_open_move(&self, unchecked_move: u32) -> bool {
         match unchecked_move {
-            1...9 => {
-                let temp_location = Self::move_to_board_location(unchecked_move);
+            valid_range if valid_range >= 1 && valid_range <= self.cell_count() => {
+                let temp_location = self.move_to_board_location(unchecked_move);
 
-                match self.board[temp_location.0][temp_location.1].as_str() {
-                    "X" | "O" => false,
-                    _ => true,
-                }
+                self.cell_state(temp_location.0, temp_location.1) == CellState::Open
             }
             _ => false,
         }
     }
 
+    /// Gets the state of a cell of the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row of the cell.
+    /// * `col` - The column of the cell.
+    fn cell_state(&self, row: usize, col: usize) -> CellState {
+        match self.board[row][col].as_str() {
+            "X" => CellState::X,
+            "O" => CellState::O,
+            OBSTACLE => CellState::Obstacle,
+            _ => CellState::Open,
+        }
+    }
+
     /// Turns a move integer into the respective row and column board location.
     ///
     /// # Arguments
     ///
     /// * `game_move` - A move from either the player or bot.
-    fn move_to_board_location(game_move: u32) -> (usize, usize) {
-        let row = (game_move - 1) / 3;
-        let col = (game_move - 1) % 3;
+    fn move_to_board_location(&self, game_move: u32) -> (usize, usize) {
+        let cols = self.cols() as u32;
+        let row = (game_move - 1) / cols;
+        let col = (game_move - 1) % cols;
 
         (row as usize, col as usize)
     }
 
+    /// Gets the token placed on the board for a turn.
+    ///
+    /// Every placement and win check gets its token here, so the tokens follow the side that
+    /// plays X.
+    ///
+    /// # Arguments
+    ///
+    /// * `turn` - The turn to get the token for.
+    fn turn_token(&self, turn: &Turn) -> &'static str {
+        if *turn == self.x_side {
+            "X"
+        } else {
+            "O"
+        }
+    }
+
+    /// Checks that the game can still be played, i.e. it is not already over.
+    fn assert_playable(&self) -> Result<(), String> {
+        if self.is_over() {
+            Err(String::from("Game is already over."))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Determines if every cell of the board is taken.
+    fn is_full(&self) -> bool {
+        (1..=self.cell_count()).all(|game_move| !self.is_open_move(game_move))
+    }
+
     /// Get the next turn, either the player or bot.
     fn get_next_turn(&self) -> Turn {
         match self.current_turn {
@@ -213,156 +952,8608 @@ impl Game {
 
     /// Determines if game is won.
     fn game_is_won(&self) -> bool {
-        let mut all_same_row = false;
-        let mut all_same_col = false;
+        self.win_lines.iter().any(|line| {
+            let (first_row, first_col) = line[0];
 
-        for index in 0..3 {
-            all_same_row |= self.board[index][0] == self.board[index][1]
-                && self.board[index][1] == self.board[index][2];
-            all_same_col |= self.board[0][index] == self.board[1][index]
-                && self.board[1][index] == self.board[2][index];
-        }
+            line.iter()
+                .all(|&(row, col)| self.board[row][col] == self.board[first_row][first_col])
+        })
+    }
 
-        let all_same_diag_1 =
-            self.board[0][0] == self.board[1][1] && self.board[1][1] == self.board[2][2];
-        let all_same_diag_2 =
-            self.board[0][2] == self.board[1][1] && self.board[1][1] == self.board[2][0];
+    /// Gets the lines of board locations that win the game when filled by one side.
+    ///
+    /// These are the rows, the columns, and the diagonals of the win length, in the enabled win
+    /// directions, that don't pass through an obstacle. On a toroidal board, the lines wrap
+    /// around the edges, so e.g. every broken diagonal is a line as well. A wrapped line that
+    /// comes back onto one of its own cells, when the board is narrower than the win length, is
+    /// not a line.
+    fn lines(&self) -> Vec<Vec<(usize, usize)>> {
+        let rows = self.rows() as isize;
+        let cols = self.cols() as isize;
+        let length = self.win_length as isize;
+        let mut seen = HashSet::new();
+        let mut lines = Vec::new();
 
-        all_same_row || all_same_col || all_same_diag_1 || all_same_diag_2
-    }
+        let steps = [(0, 1), (1, 0), (1, 1), (1, -1)];
+        let enabled_steps = steps.iter().filter(|&&step| match step {
+            (0, _) => self.win_directions.rows,
+            (_, 0) => self.win_directions.cols,
+            _ => self.win_directions.diagonals,
+        });
 
-    /// Determines if player wants to play again.
-    fn player_is_finished() -> bool {
-        let mut player_input = String::new();
+        for &(row_step, col_step) in enabled_steps {
+            for row in 0..rows {
+                for col in 0..cols {
+                    let end_row = row + (length - 1) * row_step;
+                    let end_col = col + (length - 1) * col_step;
+                    let in_bounds = end_row < rows && end_col >= 0 && end_col < cols;
 
-        println!("Are you finished playing (y/n)?:");
+                    if !in_bounds && !self.toroidal {
+                        continue;
+                    }
 
-        match io::stdin().read_line(&mut player_input) {
-            Ok(_) => {
-                let temp_input = player_input.to_lowercase();
+                    let line: Vec<(usize, usize)> = (0..length)
+                        .map(|index| (row + index * row_step, col + index * col_step))
+                        .map(|(cell_row, cell_col)| {
+                            (
+                                cell_row.rem_euclid(rows) as usize,
+                                cell_col.rem_euclid(cols) as usize,
+                            )
+                        })
+                        .collect();
+                    let mut line_key = line.clone();
+                    let through_obstacle = line.iter().any(|&(cell_row, cell_col)| {
+                        self.cell_state(cell_row, cell_col) == CellState::Obstacle
+                    });
 
-                temp_input.trim() == "y" || temp_input.trim() == "yes"
+                    line_key.sort();
+
+                    let repeats_cell = line_key.windows(2).any(|pair| pair[0] == pair[1]);
+
+                    if !through_obstacle && !repeats_cell && seen.insert(line_key) {
+                        lines.push(line);
+                    }
+                }
+            }
+        }
+
+        lines
+    }
+
+    /// Gets an empty board, with each cell holding its move number.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows.
+    /// * `cols` - The number of columns.
+    fn empty_board(rows: usize, cols: usize) -> Board {
+        (0..rows)
+            .map(|row| {
+                (0..cols)
+                    .map(|col| (row * cols + col + 1).to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Gets the number of rows of the board.
+    fn rows(&self) -> usize {
+        self.board.len()
+    }
+
+    /// Gets the number of columns of the board.
+    fn cols(&self) -> usize {
+        self.board[0].len()
+    }
+
+    /// Gets a move that immediately wins the game for a side, if there is one.
+    ///
+    /// # Arguments
+    ///
+    /// * `turn` - The side to find a winning move for.
+    fn winning_move(&self, turn: Turn) -> Option<u32> {
+        let token = self.turn_token(&turn);
+
+        self.available_moves().into_iter().find(|&game_move| {
+            let location = self.move_to_board_location(game_move);
+
+            self.win_lines.iter().any(|line| {
+                line.contains(&location)
+                    && line
+                        .iter()
+                        .all(|&(row, col)| (row, col) == location || self.board[row][col] == token)
+            })
+        })
+    }
+
+    /// Searches the game tree with minimax, pruning the branches that cannot change the result
+    /// with alpha-beta pruning.
+    ///
+    /// Scores are from the perspective of the side to move and count plies from the root of the
+    /// search, so a win `d` plies from the root scores `100 - d` for the winner. Returns the score
+    /// of the position, exact when it lies between `alpha` and `beta`, and the number of nodes
+    /// visited.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The number of plies from the root of the search to this position.
+    /// * `alpha` - The score the side to move is already guaranteed elsewhere.
+    /// * `beta` - The score the other side is already guaranteed elsewhere, negated.
+    fn alpha_beta(&mut self, depth: u32, mut alpha: i32, beta: i32) -> (i32, u64) {
+        if self.game_is_won() {
+            // The side that just moved completed a line.
+            return (depth as i32 - 100, 1);
+        }
+
+        if self.is_full() {
+            return (0, 1);
+        }
+
+        let mut best = i32::MIN;
+        let mut nodes = 1;
+
+        for game_move in self.available_moves() {
+            self.make_move(game_move);
+
+            let (score, child_nodes) = self.alpha_beta(depth + 1, -beta, -alpha);
+
+            self.unmake_move(game_move);
+
+            best = best.max(-score);
+            alpha = alpha.max(best);
+            nodes += child_nodes;
+
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        (best, nodes)
+    }
+
+    /// Gets the best move for the side to move with iterative deepening, searching one ply deeper
+    /// at a time until the search is exact or the budget elapses.
+    ///
+    /// Returns the best move of the deepest search finished in time. The shallowest search
+    /// always finishes, so there is always a move.
+    ///
+    /// # Arguments
+    ///
+    /// * `budget` - The time the search may take.
+    /// * `moves` - The open moves to choose from, at least one.
+    fn best_move_within(&mut self, budget: Duration, moves: &[u32]) -> u32 {
+        let deadline = Instant::now() + budget;
+        let mut best_move = moves[0];
+
+        for max_depth in 1..=moves.len() as u32 {
+            let mut iteration_move = moves[0];
+            let mut iteration_score = i32::MIN;
+            let mut exact = true;
+
+            for &game_move in moves {
+                self.make_move(game_move);
+
+                let result = self.limited_alpha_beta(1, max_depth, -i32::MAX, i32::MAX, deadline);
+
+                self.unmake_move(game_move);
+
+                let (score, cut_off) = match result {
+                    Some(result) => result,
+                    None => return best_move,
+                };
+
+                exact &= !cut_off;
+
+                if -score > iteration_score {
+                    iteration_move = game_move;
+                    iteration_score = -score;
+                }
+            }
+
+            best_move = iteration_move;
+
+            if exact {
+                break;
+            }
+        }
+
+        best_move
+    }
+
+    /// Searches the game tree like `alpha_beta`, but only to a maximum depth and until a
+    /// deadline. Positions at the maximum depth score by `horizon_score`.
+    ///
+    /// Returns the score of the position and whether the search was cut off at the maximum
+    /// depth, or `None` once the deadline passes.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The number of plies from the root of the search to this position.
+    /// * `max_depth` - The number of plies from the root of the search to stop at.
+    /// * `alpha` - The score the side to move is already guaranteed elsewhere.
+    /// * `beta` - The score the other side is already guaranteed elsewhere, negated.
+    /// * `deadline` - When the search gives up.
+    fn limited_alpha_beta(
+        &mut self,
+        depth: u32,
+        max_depth: u32,
+        mut alpha: i32,
+        beta: i32,
+        deadline: Instant,
+    ) -> Option<(i32, bool)> {
+        if self.game_is_won() {
+            // The side that just moved completed a line.
+            return Some((depth as i32 - 100, false));
+        }
+
+        if self.is_full() {
+            return Some((0, false));
+        }
+
+        if depth >= max_depth {
+            return Some((self.horizon_score(max_depth), true));
+        }
+
+        if Instant::now() >= deadline {
+            return None;
+        }
+
+        let mut best = i32::MIN;
+        let mut cut_off = false;
+
+        for game_move in self.available_moves() {
+            self.make_move(game_move);
+
+            let result = self.limited_alpha_beta(depth + 1, max_depth, -beta, -alpha, deadline);
+
+            self.unmake_move(game_move);
+
+            let (score, child_cut_off) = result?;
+
+            best = best.max(-score);
+            alpha = alpha.max(best);
+            cut_off |= child_cut_off;
+
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        Some((best, cut_off))
+    }
+
+    /// Scores a position at the maximum depth of a depth-limited search by its `control_score`,
+    /// from the perspective of the side to move. The score is scaled to stay below the score of
+    /// any win or loss within the maximum depth, so a forced result always outweighs it.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_depth` - The number of plies from the root of the search to the position.
+    fn horizon_score(&self, max_depth: u32) -> i32 {
+        let lines = self.win_lines.len() as i32;
+        let scale = (99 - max_depth as i32).max(0);
+
+        if lines == 0 {
+            return 0;
+        }
+
+        let control = match self.current_turn {
+            Turn::Player => self.control_score(),
+            Turn::Bot => -self.control_score(),
+        };
+
+        control * scale / lines
+    }
+
+    /// Scores how much of the board each side controls from the perspective of the player. See
+    /// `Game::control_score`.
+    fn control_score(&self) -> i32 {
+        let player_token = self.turn_token(&Turn::Player);
+        let bot_token = self.turn_token(&Turn::Bot);
+
+        self.win_lines
+            .iter()
+            .map(|line| {
+                let has_token =
+                    |token: &str| line.iter().any(|&(row, col)| self.board[row][col] == token);
+
+                match (has_token(player_token), has_token(bot_token)) {
+                    (true, false) => 1,
+                    (false, true) => -1,
+                    _ => 0,
+                }
+            })
+            .sum()
+    }
+
+    /// Counts the move orders that lead to the position from an empty board, by taking back the
+    /// moves one at a time. A taken back move must leave a position where nobody has won yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `counts` - The counts of the positions already taken back.
+    fn retrograde_count(&mut self, counts: &mut HashMap<String, u64>) -> u64 {
+        if self
+            .board
+            .iter()
+            .flatten()
+            .all(|cell| cell != "X" && cell != "O")
+        {
+            return 1;
+        }
+
+        let key = format!("{:?} {:?}", self.current_turn, self.board);
+
+        if let Some(&count) = counts.get(&key) {
+            return count;
+        }
+
+        let turn = self.current_turn;
+        let last_turn = self.get_next_turn();
+        let token = self.turn_token(&last_turn).to_string();
+        let mut count: u64 = 0;
+
+        for game_move in 1..=self.cell_count() {
+            let (row, col) = self.move_to_board_location(game_move);
+
+            if self.board[row][col] != token {
+                continue;
+            }
+
+            self.board[row][col] = game_move.to_string();
+            self.current_turn = last_turn;
+
+            if self.winner().is_none() {
+                count = count.saturating_add(self.retrograde_count(counts));
+            }
+
+            self.board[row][col] = token.clone();
+            self.current_turn = turn;
+        }
+
+        counts.insert(key, count);
+        count
+    }
+
+    /// Evaluates a move with minimax by playing it, searching, and then taking it back.
+    ///
+    /// Returns the outcome for the side making the move and the number of plies until the game
+    /// ends, including the move itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `valid_move` - A move that has already been validated.
+    fn evaluate_move(&mut self, valid_move: u32) -> (Outcome, u32) {
+        // A draw only ends the game once the board is full.
+        let open_cells = self.available_moves().len() as u32;
+
+        self.make_move(valid_move);
+
+        let (score, _) = self.alpha_beta(1, -i32::MAX, i32::MAX);
+
+        self.unmake_move(valid_move);
+
+        let (outcome, plies) = match score {
+            score if score > 0 => (Outcome::Win, (100 - score) as u32),
+            score if score < 0 => (Outcome::Loss, (100 + score) as u32),
+            _ => (Outcome::Draw, open_cells),
+        };
+
+        (outcome.flip(), plies)
+    }
+
+    /// Plays a move for searching, without recording it in the history.
+    ///
+    /// # Arguments
+    ///
+    /// * `valid_move` - A move that has already been validated.
+    fn make_move(&mut self, valid_move: u32) {
+        let (row, col) = self.move_to_board_location(valid_move);
+
+        self.board[row][col] = self.turn_token(&self.current_turn).to_string();
+        self.current_turn = self.get_next_turn();
+    }
+
+    /// Takes back a move played with `make_move`.
+    ///
+    /// # Arguments
+    ///
+    /// * `played_move` - The last move played with `make_move`.
+    fn unmake_move(&mut self, played_move: u32) {
+        let (row, col) = self.move_to_board_location(played_move);
+
+        self.current_turn = self.get_next_turn();
+        self.board[row][col] = played_move.to_string();
+    }
+}
+
+impl Default for Engine {
+    fn default() -> Engine {
+        Engine::new()
+    }
+}
+
+/// The game represented as a struct.
+///
+/// A `Game` is `Send`, so it can be shared between threads behind an `Arc<Mutex<Game>>`.
+#[derive(Clone, Debug)]
+pub struct Game {
+    /// The board, turn, and rules of the game.
+    engine: Engine,
+    /// The moves played so far, in order.
+    history: Vec<u32>,
+    /// The token each move of the history overwrote, if any, so taking the move back restores it.
+    overwritten_tokens: Vec<Option<String>>,
+    /// The scoreboard of the session.
+    scoreboard: Scoreboard,
+    /// The options set by the builder methods, kept up to date by each of them.
+    config: Config,
+    /// Where to send the events of the session for spectators, if anywhere.
+    event_sender: Option<Sender<GameEvent>>,
+    /// The narrator of the moves, if there is one.
+    commentator: Option<Commentator>,
+    /// The bot's most recent move, until the player moves again.
+    last_bot_move: Option<u32>,
+    /// The random number generator of the bot.
+    rng: StdRng,
+    /// The custom check of the player's moves, if there is one.
+    move_validator: Option<MoveValidator>,
+    /// Whether the player chose to play on after the mercy rule's offer in this game.
+    mercy_declined: bool,
+    /// The moves the bot played in the session, in order.
+    bot_moves: Vec<u32>,
+    /// The moves the bot is forced to play next, in order, before it falls back to its strategy.
+    bot_script: VecDeque<u32>,
+    /// The time each human move of the session took, with the side that played it.
+    move_times: Vec<(Turn, Duration)>,
+    /// The ranked moves of the last searched position, with the hash of the position, so the
+    /// bot's move, its taunt, and any analysis of a turn share one search.
+    evaluation_cache: RefCell<Option<(u64, RankedMoves)>>,
+    /// The number of searches for ranked moves that missed the evaluation cache.
+    searches: Cell<u64>,
+    /// The decision behind the bot's most recent move, if the bot has moved.
+    last_decision: Option<Decision>,
+}
+
+impl Game {
+    /// Constructs a `Game` object.
+    ///
+    /// The board will default to a vector of chars indicating the available moves, and the first
+    /// turn will default to the player. For fun, a user could randomize the starting player.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new();
+    /// ```
+    pub fn new() -> Game {
+        let engine = Engine::new();
+        let config = Config {
+            rows: engine.rows(),
+            cols: engine.cols(),
+            win_length: engine.win_length,
+            toroidal: engine.toroidal,
+            win_directions: engine.win_directions,
+            obstacles: Vec::new(),
+            handicap_stone: None,
+            allow_overwrite: engine.allow_overwrite,
+            difficulty: Difficulty::Easy,
+            adaptive_difficulty: false,
+            tie_break: TieBreak::First,
+            token_rule: TokenRule::FixedSide,
+            seed: None,
+            search_budget: None,
+            time_controls: None,
+            round_limit: None,
+            two_player: false,
+            fog_of_war: false,
+            copycat: false,
+            teaching: false,
+            challenge: false,
+            mercy_rule: false,
+            confirm_moves: false,
+            bot_avoid_center_opening: false,
+            bot_script: Vec::new(),
+            collapse_symmetric: false,
+            think_out_loud: false,
+            explain_moves: false,
+            auto_continue: false,
+            hold_result: false,
+            speed_leaderboard: false,
+            show_commands_hint: false,
+            theme: Theme::Monochrome,
+            render_options: RenderOptions::default(),
+            show_empty_as: EmptyStyle::Number,
+            mark_last_move: false,
+            highlight_legal: false,
+            show_move_numbers_legend: false,
+            interrupt_save_path: None,
+            autosave_path: None,
+            logfile_path: None,
+            score_path: None,
+        };
+
+        Game {
+            engine,
+            history: Vec::new(),
+            overwritten_tokens: Vec::new(),
+            scoreboard: Scoreboard::default(),
+            config,
+            event_sender: None,
+            commentator: None,
+            last_bot_move: None,
+            rng: StdRng::from_seed(&[rand::random::<usize>()]),
+            move_validator: None,
+            mercy_declined: false,
+            bot_moves: Vec::new(),
+            bot_script: VecDeque::new(),
+            move_times: Vec::new(),
+            evaluation_cache: RefCell::new(None),
+            searches: Cell::new(0),
+            last_decision: None,
+        }
+    }
+
+    /// Sets the size of the square board, which also resets the board and the win length to the
+    /// new size, and clears the obstacles and the handicap stone.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The number of rows and columns, between 3 and 100.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_size(4).unwrap();
+    ///
+    /// assert_eq!(game.available_moves().len(), 16);
+    /// assert!(Game::new().with_size(2).is_err());
+    /// ```
+    pub fn with_size(mut self, size: usize) -> Result<Game, String> {
+        Self::check_size(size)?;
+
+        self.engine.board = Engine::empty_board(size, size);
+        self.history.clear();
+        self.overwritten_tokens.clear();
+        self.last_bot_move = None;
+        self.config.obstacles.clear();
+        self.config.handicap_stone = None;
+        self.engine.win_length = size;
+        self.engine.win_lines = self.engine.lines();
+        self.sync_board_config();
+
+        Ok(self)
+    }
+
+    /// Sets the number of tokens in a row needed to win, which defaults to the board size.
+    ///
+    /// # Arguments
+    ///
+    /// * `win_length` - The win length, between 3 and the longer side of the board.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_size(5).unwrap().with_win_length(4).unwrap();
+    ///
+    /// assert!(Game::new().with_win_length(4).is_err());
+    /// ```
+    pub fn with_win_length(mut self, win_length: usize) -> Result<Game, String> {
+        let longest = self.engine.rows().max(self.engine.cols());
+
+        if win_length < MIN_SIZE || win_length > longest {
+            return Err(format!(
+                "The win length must be between {} and {}!",
+                MIN_SIZE, longest
+            ));
+        }
+
+        self.engine.win_length = win_length;
+        self.engine.win_lines = self.engine.lines();
+        self.sync_board_config();
+
+        Ok(self)
+    }
+
+    /// Sets the dimensions of a rectangular board and the win length, which also resets the
+    /// board and clears the obstacles and the handicap stone. The moves are numbered row by row,
+    /// as on a square board.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows, between 1 and 100.
+    /// * `cols` - The number of columns, between 1 and 100.
+    /// * `win_length` - The win length, between 3 and the longer side of the board.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_dims(2, 5, 3).unwrap();
+    ///
+    /// assert_eq!(game.available_moves().len(), 10);
+    /// assert!(Game::new().with_dims(2, 2, 3).is_err());
+    /// ```
+    pub fn with_dims(
+        mut self,
+        rows: usize,
+        cols: usize,
+        win_length: usize,
+    ) -> Result<Game, String> {
+        if !(1..=MAX_SIZE).contains(&rows) || !(1..=MAX_SIZE).contains(&cols) {
+            return Err(format!(
+                "The number of rows and columns must be between 1 and {}!",
+                MAX_SIZE
+            ));
+        }
+
+        self.engine.board = Engine::empty_board(rows, cols);
+        self.history.clear();
+        self.overwritten_tokens.clear();
+        self.last_bot_move = None;
+        self.config.obstacles.clear();
+        self.config.handicap_stone = None;
+        self.with_win_length(win_length)
+    }
+
+    /// Sets a handicap stone, a token of the player placed on a cell before each game, so the bot
+    /// always moves first. Set it after the board size, since resizing clears the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The cell of the handicap stone, between 1 and the number of cells, and not an
+    ///   obstacle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_handicap_stone(5).unwrap();
+    ///
+    /// assert_eq!(game.to_notation(), "----X----");
+    /// assert!(Game::new().with_handicap_stone(10).is_err());
+    /// ```
+    pub fn with_handicap_stone(mut self, cell: u32) -> Result<Game, String> {
+        if cell < 1 || cell > self.engine.cell_count() {
+            return Err(format!(
+                "The handicap stone must be between 1 and {}!",
+                self.engine.cell_count()
+            ));
+        }
+
+        if self.config.obstacles.contains(&cell) {
+            return Err(String::from("The handicap stone cannot be on an obstacle!"));
+        }
+
+        self.config.handicap_stone = Some(cell);
+        self.reset();
+
+        Ok(self)
+    }
+
+    /// Sets obstacles, cells that are never played and that no line passes through, for a
+    /// variant board. The obstacles are placed again before each game. Set them after the board
+    /// size, since resizing clears the obstacles. Obstacles are shown as "#" in the board
+    /// notation and the saves.
+    ///
+    /// # Arguments
+    ///
+    /// * `cells` - The open cells of the obstacles, between 1 and the number of cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let mut game = Game::new().with_obstacles(&[5]).unwrap();
+    ///
+    /// assert!(game.apply_move(5).is_err());
+    /// assert!(Game::new().with_obstacles(&[10]).is_err());
+    /// ```
+    pub fn with_obstacles(mut self, cells: &[u32]) -> Result<Game, String> {
+        for &cell in cells {
+            if !self.engine.is_open_move(cell) {
+                return Err(format!(
+                    "An obstacle must be on an open cell between 1 and {}!",
+                    self.engine.cell_count()
+                ));
+            }
+
+            self.place_obstacle(cell);
+        }
+
+        self.config.obstacles.extend_from_slice(cells);
+        self.engine.win_lines = self.engine.lines();
+
+        Ok(self)
+    }
+
+    /// Sets the difficulty of the bot, which defaults to easy.
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty` - The difficulty of the bot.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Difficulty, Game};
+    ///
+    /// let game = Game::new().with_difficulty(Difficulty::Hard);
+    /// ```
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Game {
+        self.config.difficulty = difficulty;
+        self
+    }
+
+    /// Sets whether the difficulty of the bot ramps up within a game, overriding the fixed
+    /// difficulty. The bot plays randomly in the opening, with heuristics in the middle game, and
+    /// with minimax once only a few cells are left. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `adaptive_difficulty` - Whether the difficulty ramps up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_adaptive_difficulty(true);
+    /// ```
+    pub fn with_adaptive_difficulty(mut self, adaptive_difficulty: bool) -> Game {
+        self.config.adaptive_difficulty = adaptive_difficulty;
+        self
+    }
+
+    /// Sets the preference for choosing among equally good moves of the bot, which defaults to
+    /// the first move searched.
+    ///
+    /// # Arguments
+    ///
+    /// * `tie_break` - The preference among equally good moves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Game, TieBreak};
+    ///
+    /// let game = Game::new().with_tie_break(TieBreak::Random);
+    /// ```
+    pub fn with_tie_break(mut self, tie_break: TieBreak) -> Game {
+        self.config.tie_break = tie_break;
+        self
+    }
+
+    /// Sets how long the hard bot may search for a move, to keep large boards responsive.
+    ///
+    /// With a budget, the bot searches on any board with iterative deepening, one ply deeper at
+    /// a time, and plays the best move of the deepest search finished in time. The tie-break
+    /// preference is not used. The default board is searched fully within any reasonable
+    /// budget. There is no budget by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `search_budget` - The time the bot may search for a move.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_search_budget(Duration::from_millis(500));
+    /// ```
+    pub fn with_search_budget(mut self, search_budget: Duration) -> Game {
+        self.config.search_budget = Some(search_budget);
+        self
+    }
+
+    /// Sets the time the player and the bot each have to make a move, e.g. to give the player more
+    /// time than the bot. In two-player mode, the second player has the bot's time.
+    ///
+    /// The time is announced before each move. A player who takes longer forfeits the round,
+    /// while the bot's time caps how long the hard bot searches, like `with_search_budget`. The
+    /// moves are not timed by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The time the player has to make a move.
+    /// * `bot` - The time the bot has to make a move.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_time_controls(Duration::from_secs(30), Duration::from_secs(1));
+    /// ```
+    pub fn with_time_controls(mut self, player: Duration, bot: Duration) -> Game {
+        self.config.time_controls = Some((player, bot));
+        self
+    }
+
+    /// Seeds the random number generator of the bot, so its games are repeatable. The seed is
+    /// printed with the end of session summary. By default, the generator is seeded randomly.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_seed(42);
+    /// ```
+    pub fn with_seed(mut self, seed: u64) -> Game {
+        self.rng = StdRng::from_seed(&[seed as usize]);
+        self.config.seed = Some(seed);
+        self
+    }
+
+    /// Constructs a `Game` object by asking the player for the board size, the win length, the
+    /// difficulty, and the color theme, re-prompting on invalid answers.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to read the answers from.
+    /// * `output` - The output to write the prompts to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let mut input = "4\n3\nhard\ndefault\n".as_bytes();
+    /// let mut output = Vec::new();
+    ///
+    /// let game = Game::configure_interactively(&mut input, &mut output).unwrap();
+    /// ```
+    pub fn configure_interactively<I: Input, W: Write>(
+        input: &mut I,
+        output: &mut W,
+    ) -> io::Result<Game> {
+        let game = Self::prompt(
+            input,
+            output,
+            "Please enter the board size (an integer between 3 and 100):",
+            |answer| Self::parse_number(answer).and_then(|size| Game::new().with_size(size)),
+        )?;
+
+        let length_question = format!(
+            "Please enter the win length (an integer between 3 and {}):",
+            game.engine.board.len()
+        );
+        let game = Self::prompt(input, output, &length_question, |answer| {
+            Self::parse_number(answer).and_then(|length| game.clone().with_win_length(length))
+        })?;
+
+        let difficulty = Self::prompt(
+            input,
+            output,
+            "Please enter the difficulty (easy, medium, or hard):",
+            |answer| answer.parse::<Difficulty>(),
+        )?;
+
+        let theme = Self::prompt(
+            input,
+            output,
+            "Please enter the color theme (default, high-contrast, or monochrome):",
+            |answer| answer.parse::<Theme>(),
+        )?;
+
+        Ok(game.with_difficulty(difficulty).with_theme(theme))
+    }
+
+    /// Sets whether the most recently played token is marked with brackets when printing the
+    /// board, e.g. "|[X]|". Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `mark_last_move` - Whether to mark the last move.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_mark_last_move(true);
+    /// ```
+    pub fn with_mark_last_move(mut self, mark_last_move: bool) -> Game {
+        self.config.mark_last_move = mark_last_move;
+        self
+    }
+
+    /// Sets where to save the in-progress game when the session is interrupted (Ctrl-C). By
+    /// default, nothing is saved.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the save file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_interrupt_save(Path::new("tic_tac_toe.save"));
+    /// ```
+    pub fn with_interrupt_save(mut self, path: &Path) -> Game {
+        self.config.interrupt_save_path = Some(path.to_path_buf());
+        self
+    }
+
+    /// Sets where to save the game after every move, in the format of `save`, e.g. to resume it
+    /// after a crash. The save is written to a temporary file first and then renamed, so a crash
+    /// mid-write never corrupts it. By default, nothing is saved.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the save file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_autosave(Path::new("tic_tac_toe.save"));
+    /// ```
+    pub fn with_autosave(mut self, path: &Path) -> Game {
+        self.config.autosave_path = Some(path.to_path_buf());
+        self
+    }
+
+    /// Sets a file to append every completed game to, as a line with the final board notation,
+    /// the result for the player ("win", "loss", or "draw"), and the Unix timestamp in seconds.
+    /// By default, nothing is logged. Failing to write the file ends the session with the error.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the log file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_logfile(Path::new("tic_tac_toe.log"));
+    /// ```
+    pub fn with_logfile(mut self, path: &Path) -> Game {
+        self.config.logfile_path = Some(path.to_path_buf());
+        self
+    }
+
+    /// Sets a file to keep the scoreboard in between sessions, so the all-time record is
+    /// remembered. The scoreboard is loaded from the file, which is created if it is absent,
+    /// and saved to it after every round. A corrupt file resets the scoreboard with a warning.
+    /// Failing to save the file ends the session with the error.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the score file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_persistent_score(Path::new("tic_tac_toe.score"));
+    /// ```
+    pub fn with_persistent_score(mut self, path: &Path) -> Game {
+        self.config.score_path = Some(path.to_path_buf());
+        self.scoreboard = match fs::read_to_string(path) {
+            Ok(contents) => Self::parse_score(&contents).unwrap_or_else(|err| {
+                println!("Warning: {} The score is reset.", err);
+                Scoreboard::default()
+            }),
+            Err(_) => Scoreboard::default(),
+        };
+
+        if let Err(err) = self.save_score() {
+            println!("Warning: could not save the score: {}", err);
+        }
+
+        self
+    }
+
+    /// Sets a channel to send the events of the session to as they occur, e.g. for a UI or a
+    /// logger on another thread. By default, no events are sent. Events are dropped once the
+    /// receiver hangs up.
+    ///
+    /// # Arguments
+    ///
+    /// * `sender` - The sending half of the channel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    /// use tic_tac_toe::game::{GameEvent, Game, Turn};
+    ///
+    /// let (sender, receiver) = mpsc::channel();
+    /// let mut game = Game::new().with_event_sender(sender);
+    ///
+    /// game.apply_move(5).unwrap();
+    ///
+    /// assert_eq!(receiver.recv(), Ok(GameEvent::MovePlayed(Turn::Player, 5)));
+    /// ```
+    pub fn with_event_sender(mut self, sender: Sender<GameEvent>) -> Game {
+        self.event_sender = Some(sender);
+        self
+    }
+
+    /// Sets a commentator to narrate every move, called with the game after the move, the side
+    /// that moved, and the move. The crate's own narration is `game::commentary`. By default,
+    /// nothing is narrated.
+    ///
+    /// # Arguments
+    ///
+    /// * `commentator` - The narration of a move.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{self, Game};
+    ///
+    /// let game = Game::new().with_commentator(|game, side, game_move| {
+    ///     println!("{}", game::commentary(game, side, game_move))
+    /// });
+    /// ```
+    pub fn with_commentator<F>(mut self, commentator: F) -> Game
+    where
+        F: FnMut(&Game, Turn, u32) + Send + 'static,
+    {
+        self.commentator = Some(Commentator(Arc::new(Mutex::new(commentator))));
+        self
+    }
+
+    /// Sets whether the bot avoids the center (5) when it opens on an empty board, for more
+    /// varied games. Later moves are unaffected. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `bot_avoid_center_opening` - Whether the bot avoids the center opening.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_bot_avoid_center_opening(true);
+    /// ```
+    pub fn with_bot_avoid_center_opening(mut self, bot_avoid_center_opening: bool) -> Game {
+        self.config.bot_avoid_center_opening = bot_avoid_center_opening;
+        self
+    }
+
+    /// Sets whether the bot is a copycat, mirroring the player's last move across the center of
+    /// the board, e.g. 9 after 1. If the mirrored cell is taken, the bot plays at its
+    /// difficulty instead. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `copycat` - Whether the bot is a copycat.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_copycat(true);
+    /// ```
+    pub fn with_copycat(mut self, copycat: bool) -> Game {
+        self.config.copycat = copycat;
+        self
+    }
+
+    /// Sets whether the bot is a teaching bot, for practicing to force draws. The teaching bot
+    /// only plays moves that keep the game drawn, so it never tries to win, not even after a
+    /// blunder of the player, and the player gets clean drawn games unless they lose a drawn
+    /// position. Once no move draws, or too many cells are open to search, the bot plays at its
+    /// difficulty. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `teaching` - Whether the bot is a teaching bot.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_teaching(true);
+    /// ```
+    pub fn with_teaching(mut self, teaching: bool) -> Game {
+        self.config.teaching = teaching;
+        self
+    }
+
+    /// Sets whether a move may overwrite a taken cell, to author arbitrary positions when
+    /// testing or writing puzzles.
+    ///
+    /// This is a debug mode, so it has no effect in release builds. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_overwrite` - Whether a move may overwrite a taken cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_allow_overwrite(true);
+    /// ```
+    pub fn with_allow_overwrite(mut self, allow_overwrite: bool) -> Game {
+        self.engine.allow_overwrite = allow_overwrite && cfg!(debug_assertions);
+        self.sync_board_config();
+        self
+    }
+
+    /// Sets whether the lines wrap around the edges of the board, as if it were a torus.
+    ///
+    /// A wrapped diagonal continues from the right edge to the left edge, so e.g. 2, 6, and 7
+    /// form a line on the default board. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `toroidal` - Whether the lines wrap around.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_toroidal(true);
+    /// ```
+    pub fn with_toroidal(mut self, toroidal: bool) -> Game {
+        self.engine.toroidal = toroidal;
+        self.engine.win_lines = self.engine.lines();
+        self.sync_board_config();
+        self
+    }
+
+    /// Sets the directions of the lines that win the game, e.g. no diagonals for an easier game.
+    /// The bot and the threat detection only consider the lines of these directions. Defaults
+    /// to every direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `win_directions` - The directions that win, at least one of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Game, WinDirections};
+    ///
+    /// let orthogonal = WinDirections {
+    ///     diagonals: false,
+    ///     ..WinDirections::default()
+    /// };
+    ///
+    /// let game = Game::new().with_win_directions(orthogonal).unwrap();
+    /// ```
+    pub fn with_win_directions(mut self, win_directions: WinDirections) -> Result<Game, String> {
+        if !win_directions.rows && !win_directions.cols && !win_directions.diagonals {
+            return Err(String::from("At least one win direction must be enabled!"));
+        }
+
+        self.engine.win_directions = win_directions;
+        self.engine.win_lines = self.engine.lines();
+        self.sync_board_config();
+
+        Ok(self)
+    }
+
+    /// Sets whether the fog of war is on, a memory challenge where the bot's most recent move is
+    /// printed as "?" until the player makes their move. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `fog_of_war` - Whether the fog of war is on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_fog_of_war(true);
+    /// ```
+    pub fn with_fog_of_war(mut self, fog_of_war: bool) -> Game {
+        self.config.fog_of_war = fog_of_war;
+        self
+    }
+
+    /// Sets whether the still open cells are marked with parentheses when printing the board,
+    /// e.g. "|(5)|", to show where a move can be played. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `highlight_legal` - Whether to mark the open cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_highlight_legal(true);
+    /// ```
+    pub fn with_highlight_legal(mut self, highlight_legal: bool) -> Game {
+        self.config.highlight_legal = highlight_legal;
+        self
+    }
+
+    /// Sets whether a reference grid with every move number is printed beneath the board, so the
+    /// number of a cell can still be found once its token covers it. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `show_move_numbers_legend` - Whether to print the reference grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_show_move_numbers_legend(true);
+    /// ```
+    pub fn with_show_move_numbers_legend(mut self, show_move_numbers_legend: bool) -> Game {
+        self.config.show_move_numbers_legend = show_move_numbers_legend;
+        self
+    }
+
+    /// Sets how the open cells are rendered when printing the board, e.g. as blanks for a less
+    /// cluttered board. Defaults to their move numbers.
+    ///
+    /// # Arguments
+    ///
+    /// * `show_empty_as` - How to render the open cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{EmptyStyle, Game};
+    ///
+    /// let game = Game::new().with_show_empty_as(EmptyStyle::Dot);
+    /// ```
+    pub fn with_show_empty_as(mut self, show_empty_as: EmptyStyle) -> Game {
+        self.config.show_empty_as = show_empty_as;
+        self
+    }
+
+    /// Sets the color theme of the rendered board, which colors the tokens, the borders, and the
+    /// markers. Defaults to monochrome, without any colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - The color theme.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Game, Theme};
+    ///
+    /// let game = Game::new().with_theme(Theme::HighContrast);
+    /// ```
+    pub fn with_theme(mut self, theme: Theme) -> Game {
+        self.config.theme = theme;
+        self
+    }
+
+    /// Sets the spacing of the rendered board, e.g. to make it easier to read on a wide terminal.
+    ///
+    /// # Arguments
+    ///
+    /// * `render_options` - The spacing of the rendered board.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Game, RenderOptions};
+    ///
+    /// let game = Game::new().with_render_options(RenderOptions {
+    ///     padding: 2,
+    ///     row_spacing: 1,
+    /// });
+    /// ```
+    pub fn with_render_options(mut self, render_options: RenderOptions) -> Game {
+        self.config.render_options = render_options;
+        self
+    }
+
+    /// Sets whether the challenge mode is on, where the bot taunts the player with its minimax
+    /// evaluation of each move it plays. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `challenge` - Whether the challenge mode is on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_challenge(true);
+    /// ```
+    pub fn with_challenge(mut self, challenge: bool) -> Game {
+        self.config.challenge = challenge;
+        self
+    }
+
+    /// Sets whether `ranked_moves` collapses the moves leading to symmetric positions, i.e.
+    /// rotations or reflections of each other, into one. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `collapse_symmetric` - Whether to collapse symmetric moves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_collapse_symmetric(true);
+    /// ```
+    pub fn with_collapse_symmetric(mut self, collapse_symmetric: bool) -> Game {
+        self.config.collapse_symmetric = collapse_symmetric;
+        self
+    }
+
+    /// Sets whether two players play against each other, as X and O, instead of the player
+    /// against the bot. Either player can offer a draw by entering "draw". Defaults to off.
+    ///
+    /// The scoreboard counts wins for X as wins, and wins for O as losses.
+    ///
+    /// # Arguments
+    ///
+    /// * `two_player` - Whether two players play.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_two_player(true);
+    /// ```
+    pub fn with_two_player(mut self, two_player: bool) -> Game {
+        self.config.two_player = two_player;
+        self
+    }
+
+    /// Sets whether the player confirms each move before it is placed, to catch mistyped moves.
+    /// Declining a move asks for another one. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `confirm_moves` - Whether the player confirms each move.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_confirm_moves(true);
+    /// ```
+    pub fn with_confirm_moves(mut self, confirm_moves: bool) -> Game {
+        self.config.confirm_moves = confirm_moves;
+        self
+    }
+
+    /// Sets whether the move prompt ends with a one-line reminder of the commands the player can
+    /// type, e.g. "Commands: help, try, draw". Defaults to off, to keep the prompt clean.
+    ///
+    /// # Arguments
+    ///
+    /// * `show_commands_hint` - Whether the move prompt reminds of the commands.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_show_commands_hint(true);
+    /// ```
+    pub fn with_show_commands_hint(mut self, show_commands_hint: bool) -> Game {
+        self.config.show_commands_hint = show_commands_hint;
+        self
+    }
+
+    /// Sets whether the final board and the result of a round are held until the player presses
+    /// enter, before the next question of the session. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `hold_result` - Whether the result is held.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_hold_result(true);
+    /// ```
+    pub fn with_hold_result(mut self, hold_result: bool) -> Game {
+        self.config.hold_result = hold_result;
+        self
+    }
+
+    /// Sets the number of rounds the session ends after, without asking to play again after the
+    /// last round. There is no limit by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `round_limit` - The number of rounds to play.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_round_limit(3);
+    /// ```
+    pub fn with_round_limit(mut self, round_limit: u32) -> Game {
+        self.config.round_limit = Some(round_limit);
+        self
+    }
+
+    /// Sets whether the next round starts right after a result, skipping the question whether
+    /// the player is finished, e.g. to practice openings. The session then only ends at the
+    /// round limit, or when it is interrupted. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `auto_continue` - Whether the next round starts without asking.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_round_limit(5).with_auto_continue(true);
+    /// ```
+    pub fn with_auto_continue(mut self, auto_continue: bool) -> Game {
+        self.config.auto_continue = auto_continue;
+        self
+    }
+
+    /// Sets whether the mercy rule is on, where the player is offered to resign instead of
+    /// playing out a lost position. A position only counts as lost when minimax proves that
+    /// every move loses. The offer is made once per game. Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `mercy_rule` - Whether the mercy rule is on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_mercy_rule(true);
+    /// ```
+    pub fn with_mercy_rule(mut self, mercy_rule: bool) -> Game {
+        self.config.mercy_rule = mercy_rule;
+        self
+    }
+
+    /// Sets whether the session summary shows a speed leaderboard, ranking the human players by
+    /// their average move time across the rounds, along with their fastest move. Defaults to
+    /// off.
+    ///
+    /// # Arguments
+    ///
+    /// * `speed_leaderboard` - Whether the speed leaderboard is shown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_two_player(true).with_speed_leaderboard(true);
+    /// ```
+    pub fn with_speed_leaderboard(mut self, speed_leaderboard: bool) -> Game {
+        self.config.speed_leaderboard = speed_leaderboard;
+        self
+    }
+
+    /// Sets whether the bot explains why it chose each move, e.g. "blocking your threat at 3".
+    /// Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `explain_moves` - Whether the bot explains its moves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_explain_moves(true);
+    /// ```
+    pub fn with_explain_moves(mut self, explain_moves: bool) -> Game {
+        self.config.explain_moves = explain_moves;
+        self
+    }
+
+    /// Sets whether the bot thinks out loud, printing "Bot is thinking..." before it searches for
+    /// its move and the time the move took after, e.g. for slow searches on bigger boards.
+    /// Defaults to off.
+    ///
+    /// # Arguments
+    ///
+    /// * `think_out_loud` - Whether the bot thinks out loud.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_think_out_loud(true);
+    /// ```
+    pub fn with_think_out_loud(mut self, think_out_loud: bool) -> Game {
+        self.config.think_out_loud = think_out_loud;
+        self
+    }
+
+    /// Sets the rule for assigning the tokens to the sides, which defaults to the player always
+    /// playing X. With `TokenRule::FirstMoverIsX`, the tokens are assigned when the first move
+    /// of a game is played, so the bot plays X in the games it starts.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_rule` - The rule for assigning the tokens.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Game, TokenRule};
+    ///
+    /// let game = Game::new().with_token_rule(TokenRule::FirstMoverIsX);
+    /// ```
+    pub fn with_token_rule(mut self, token_rule: TokenRule) -> Game {
+        self.config.token_rule = token_rule;
+        self
+    }
+
+    /// Sets the moves the bot is forced to play, e.g. the `bot_moves` of a prior session, so a
+    /// game can be re-run exactly. Once the script is exhausted, the bot falls back to its
+    /// strategy, as it does for a scripted move that is no longer open.
+    ///
+    /// # Arguments
+    ///
+    /// * `moves` - The moves for the bot to play, in order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_scripted_bot(vec![1, 9, 3]);
+    /// ```
+    pub fn with_scripted_bot(mut self, moves: Vec<u32>) -> Game {
+        self.bot_script = moves.iter().cloned().collect();
+        self.config.bot_script = moves;
+        self
+    }
+
+    /// Sets a custom check of the player's moves, e.g. to enforce a house rule. The check runs
+    /// after the built-in validation, and a move it fails is rejected with its error. By default,
+    /// there is no custom check.
+    ///
+    /// # Arguments
+    ///
+    /// * `validator` - The check of a move, given the move and the game before it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new().with_move_validator(|game_move, _| {
+    ///     if game_move == 5 {
+    ///         Err(String::from("The center is off limits!"))
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// });
+    /// ```
+    pub fn with_move_validator<F>(mut self, validator: F) -> Game
+    where
+        F: Fn(u32, &Game) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.move_validator = Some(MoveValidator(Arc::new(validator)));
+        self
+    }
+
+    /// Constructs a `Game` object from an existing board and turn.
+    ///
+    /// The board must be square and between 3x3 and 100x100, with each cell holding either a
+    /// token ("X" or "O"), an obstacle ("#"), or the move number of that cell. The win length is
+    /// the board size.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The board to start the game from.
+    /// * `turn` - The turn to start the game from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Game, Turn};
+    ///
+    /// let board = vec![
+    ///     vec![String::from("X"), String::from("2"), String::from("3")],
+    ///     vec![String::from("4"), String::from("O"), String::from("6")],
+    ///     vec![String::from("7"), String::from("8"), String::from("9")],
+    /// ];
+    ///
+    /// let game = Game::from_board(board, Turn::Player).unwrap();
+    /// ```
+    pub fn from_board(board: Board, turn: Turn) -> Result<Game, String> {
+        let size = board.len();
+
+        Self::check_size(size)?;
+
+        if board.iter().any(|row| row.len() != size) {
+            return Err(String::from("The board must be square!"));
+        }
+
+        Self::from_rectangular_board(board, turn, size)
+    }
+
+    /// Constructs a `Game` object from an existing rectangular board, turn, and win length, with
+    /// the cells checked as for `from_board`.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The board to start the game from, between 1 and 100 rows and columns.
+    /// * `turn` - The turn to start the game from.
+    /// * `win_length` - The win length, between 3 and the longer side of the board.
+    fn from_rectangular_board(board: Board, turn: Turn, win_length: usize) -> Result<Game, String> {
+        let cols = board.first().map_or(0, |row| row.len());
+
+        if board.iter().any(|row| row.len() != cols) {
+            return Err(String::from("The board must be rectangular!"));
+        }
+
+        let mut game = Game::new().with_dims(board.len(), cols, win_length)?;
+        let mut obstacles = Vec::new();
+
+        for (index, cell) in board.iter().flat_map(|row| row.iter()).enumerate() {
+            if cell == OBSTACLE {
+                obstacles.push(index as u32 + 1);
+            } else if cell != "X" && cell != "O" && *cell != (index + 1).to_string() {
+                return Err(format!("Invalid cell \"{}\" at move {}!", cell, index + 1));
+            }
+        }
+
+        game.engine.board = board;
+        game.engine.current_turn = turn;
+        game.engine.win_lines = game.engine.lines();
+        game.config.obstacles = obstacles;
+
+        Ok(game)
+    }
+
+    /// Constructs a `Game` object from board notation.
+    ///
+    /// The notation lists the cells of a square board row by row, using "X" and "O" for tokens,
+    /// "-" for open cells, and "#" for obstacles. The player plays X, and the turn is inferred
+    /// from the number of tokens by taking X to have moved first, so O is to move when X has one
+    /// more token.
+    ///
+    /// # Arguments
+    ///
+    /// * `notation` - The board notation, e.g. "X---O----".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("X---O----").unwrap();
+    /// ```
+    pub fn from_notation(notation: &str) -> Result<Game, String> {
+        let mut game = Self::from_board(Self::notation_to_board(notation)?, Turn::Player)?;
+        let (x_count, o_count) = game.token_counts();
+
+        if x_count == o_count + 1 {
+            game.engine.current_turn = Turn::Bot;
+        } else if x_count != o_count {
+            return Err(String::from("The number of X and O tokens is not valid!"));
+        }
+
+        Ok(game)
+    }
+
+    /// Gets the board notation of the game, as read by `from_notation`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let mut game = Game::new();
+    ///
+    /// game.apply_move(5).unwrap();
+    ///
+    /// assert_eq!(game.to_notation(), "----X----");
+    /// ```
+    pub fn to_notation(&self) -> String {
+        self.engine
+            .board
+            .iter()
+            .flatten()
+            .map(|cell| match cell.as_str() {
+                "X" | "O" | OBSTACLE => cell.as_str(),
+                _ => "-",
+            })
+            .collect()
+    }
+
+    /// Renders the board as a PPM (P6) image, with black grid lines, red crosses for "X", and
+    /// blue rings for "O", e.g. to write to a ".ppm" file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("X---O----").unwrap();
+    /// let image = game.to_ppm();
+    ///
+    /// assert!(image.starts_with(b"P6\n"));
+    /// ```
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let side_length = |cells: usize| cells * PPM_CELL_SIZE + (cells + 1) * PPM_LINE_WIDTH;
+        let width = side_length(self.engine.cols());
+        let height = side_length(self.engine.rows());
+        let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+
+        for y in 0..height {
+            for x in 0..width {
+                image.extend_from_slice(&self.ppm_pixel(x, y));
+            }
+        }
+
+        image
+    }
+
+    /// Gets the color of a pixel in the PPM image of the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The column of the pixel.
+    /// * `y` - The row of the pixel.
+    fn ppm_pixel(&self, x: usize, y: usize) -> [u8; 3] {
+        let stride = PPM_CELL_SIZE + PPM_LINE_WIDTH;
+
+        if x % stride < PPM_LINE_WIDTH || y % stride < PPM_LINE_WIDTH {
+            return [0, 0, 0];
+        }
+
+        let cell_x = (x % stride - PPM_LINE_WIDTH) as isize;
+        let cell_y = (y % stride - PPM_LINE_WIDTH) as isize;
+        let last = PPM_CELL_SIZE as isize - 1;
+        let margin = PPM_CELL_SIZE as isize / 6;
+        let in_margin = cell_x >= margin
+            && cell_x <= last - margin
+            && cell_y >= margin
+            && cell_y <= last - margin;
+
+        match self.engine.board[y / stride][x / stride].as_str() {
+            "X" if in_margin
+                && ((cell_x - cell_y).abs() <= 2 || (cell_x + cell_y - last).abs() <= 2) =>
+            {
+                [220, 40, 40]
+            }
+            "O" => {
+                let distance = (2 * cell_x - last).pow(2) + (2 * cell_y - last).pow(2);
+                let outer = last - margin;
+                let inner = outer - 8;
+
+                if distance <= outer * outer && distance >= inner * inner {
+                    [40, 80, 220]
+                } else {
+                    [255, 255, 255]
+                }
+            }
+            _ => [255, 255, 255],
+        }
+    }
+
+    /// Saves the board, turn, and board options of the game to a file.
+    ///
+    /// The file holds the board notation on the first line, the turn ("player" or "bot") on the
+    /// second line, and the board options on the third line: the rows, columns, win length,
+    /// whether the board is toroidal, the side playing X, and whether rows, columns, and
+    /// diagonals win, e.g. "3 3 3 false player true true true".
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the save file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// Game::new().save(Path::new("tic_tac_toe.save")).unwrap();
+    /// ```
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let turn = match self.engine.current_turn {
+            Turn::Player => "player",
+            Turn::Bot => "bot",
+        };
+
+        let x_side = match self.engine.x_side {
+            Turn::Player => "player",
+            Turn::Bot => "bot",
+        };
+        let win_directions = self.engine.win_directions;
+        let options = format!(
+            "{} {} {} {} {} {} {} {}",
+            self.engine.rows(),
+            self.engine.cols(),
+            self.engine.win_length,
+            self.engine.toroidal,
+            x_side,
+            win_directions.rows,
+            win_directions.cols,
+            win_directions.diagonals
+        );
+
+        fs::write(
+            path,
+            format!("{}\n{}\n{}\n", self.to_notation(), turn, options),
+        )
+    }
+
+    /// Loads a game saved with `save`. A save without the board options line is loaded as a
+    /// square board with the win length of its size, and a save without the win directions in
+    /// its options is loaded with every direction winning.
+    ///
+    /// A position that cannot arise from legal play, e.g. a puzzle, is loaded with a warning.
+    /// Only boards small enough to search are checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the save file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::load(Path::new("tic_tac_toe.save")).unwrap();
+    /// ```
+    pub fn load(path: &Path) -> io::Result<Game> {
+        let contents = fs::read_to_string(path)?;
+        let mut lines = contents.lines();
+        let invalid_save = |err: String| io::Error::new(io::ErrorKind::InvalidData, err);
+
+        let notation = lines.next().unwrap_or("");
+        let turn = match lines.next().map(|line| line.trim()) {
+            Some("player") => Turn::Player,
+            Some("bot") => Turn::Bot,
+            _ => return Err(invalid_save(String::from("The save has no valid turn!"))),
+        };
+
+        let game = match lines.next() {
+            Some(options) => Self::load_with_options(notation, turn, options),
+            None => {
+                Self::notation_to_board(notation).and_then(|board| Self::from_board(board, turn))
+            }
+        }
+        .map_err(&invalid_save)?;
+
+        if game.engine.cell_count() as usize <= MAX_SEARCH_CELLS && !game.is_reachable() {
+            println!("Warning: the saved position cannot arise from legal play.");
+        }
+
+        Ok(game)
+    }
+
+    /// Constructs a `Game` object from the lines of a save with the board options line.
+    ///
+    /// # Arguments
+    ///
+    /// * `notation` - The board notation, listing the cells row by row.
+    /// * `turn` - The turn to start the game from.
+    /// * `options` - The board options, e.g. "3 3 3 false player true true true".
+    fn load_with_options(notation: &str, turn: Turn, options: &str) -> Result<Game, String> {
+        let fields: Vec<&str> = options.split_whitespace().collect();
+        let invalid_options = || String::from("The save has invalid board options!");
+
+        if fields.len() != 5 && fields.len() != 8 {
+            return Err(invalid_options());
+        }
+
+        let parse_number = |field: &str| field.parse::<usize>().map_err(|_| invalid_options());
+        let parse_flag = |field: &str| field.parse::<bool>().map_err(|_| invalid_options());
+        let (rows, cols) = (parse_number(fields[0])?, parse_number(fields[1])?);
+        let win_length = parse_number(fields[2])?;
+        let toroidal = parse_flag(fields[3])?;
+        let x_side = match fields[4] {
+            "player" => Turn::Player,
+            "bot" => Turn::Bot,
+            _ => return Err(invalid_options()),
+        };
+        let win_directions = if fields.len() == 8 {
+            WinDirections {
+                rows: parse_flag(fields[5])?,
+                cols: parse_flag(fields[6])?,
+                diagonals: parse_flag(fields[7])?,
+            }
+        } else {
+            WinDirections::default()
+        };
+
+        let cells: Vec<char> = notation.trim().chars().collect();
+
+        if cols == 0 || cells.len() != rows * cols {
+            return Err(String::from(
+                "The notation does not have the cells of the saved board!",
+            ));
+        }
+
+        let board = Self::cells_to_board(&cells, cols)?;
+        let mut game = Self::from_rectangular_board(board, turn, win_length)?
+            .with_toroidal(toroidal)
+            .with_win_directions(win_directions)?;
+
+        game.engine.x_side = x_side;
+
+        Ok(game)
+    }
+
+    /// Saves the board, turn, and board options of the game to a compact binary file.
+    ///
+    /// The file starts with the magic number "TTTB" and the format version, followed by the rows,
+    /// columns, win length, options (bit 0 for a toroidal board, bit 1 for the bot playing X, and
+    /// bits 2, 3, and 4 for rows, columns, and diagonals winning), and turn (0 for the player and
+    /// 1 for the bot) as one byte each. The cells follow row by
+    /// row, packed four to a byte from the low bits up, as 0 for an open cell, 1 for X, 2 for O,
+    /// and 3 for an obstacle.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the save file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// Game::new().save_binary(Path::new("tic_tac_toe.bin")).unwrap();
+    /// ```
+    pub fn save_binary(&self, path: &Path) -> io::Result<()> {
+        let mut bytes = BINARY_SAVE_MAGIC.to_vec();
+        let mut options = 0;
+
+        if self.engine.toroidal {
+            options |= 1;
+        }
+
+        if self.engine.x_side == Turn::Bot {
+            options |= 2;
+        }
+
+        for (bit, &wins) in [
+            self.engine.win_directions.rows,
+            self.engine.win_directions.cols,
+            self.engine.win_directions.diagonals,
+        ]
+        .iter()
+        .enumerate()
+        {
+            if wins {
+                options |= 4 << bit;
+            }
+        }
+
+        bytes.push(BINARY_SAVE_VERSION);
+        bytes.push(self.engine.rows() as u8);
+        bytes.push(self.engine.cols() as u8);
+        bytes.push(self.engine.win_length as u8);
+        bytes.push(options);
+        bytes.push(match self.engine.current_turn {
+            Turn::Player => 0,
+            Turn::Bot => 1,
+        });
+
+        let cells: Vec<u8> = self
+            .engine
+            .board
+            .iter()
+            .flatten()
+            .map(|cell| match cell.as_str() {
+                "X" => 1,
+                "O" => 2,
+                OBSTACLE => 3,
+                _ => 0,
+            })
+            .collect();
+
+        for chunk in cells.chunks(4) {
+            bytes.push(
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |byte, (index, cell)| byte | cell << (index * 2)),
+            );
+        }
+
+        fs::write(path, bytes)
+    }
+
+    /// Loads a game saved with `save_binary`, rejecting a file with another magic number or
+    /// format version. A save of version 1, before the win directions were saved, is loaded with
+    /// every direction winning.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the save file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::load_binary(Path::new("tic_tac_toe.bin")).unwrap();
+    /// ```
+    pub fn load_binary(path: &Path) -> io::Result<Game> {
+        let bytes = fs::read(path)?;
+        let invalid_save = |err: &str| io::Error::new(io::ErrorKind::InvalidData, err);
+
+        if bytes.len() < 10 || &bytes[..4] != BINARY_SAVE_MAGIC {
+            return Err(invalid_save("The file is not a binary save!"));
+        }
+
+        if bytes[4] != 1 && bytes[4] != BINARY_SAVE_VERSION {
+            return Err(invalid_save("The binary save has an unsupported version!"));
+        }
+
+        let (rows, cols) = (bytes[5] as usize, bytes[6] as usize);
+        let (win_length, options) = (bytes[7] as usize, bytes[8]);
+        let turn = match bytes[9] {
+            0 => Turn::Player,
+            1 => Turn::Bot,
+            _ => return Err(invalid_save("The binary save has no valid turn!")),
+        };
+
+        if bytes.len() != 10 + (rows * cols).div_ceil(4) {
+            return Err(invalid_save(
+                "The binary save has the wrong number of cells!",
+            ));
+        }
+
+        let mut board = Engine::empty_board(rows, cols);
+
+        for index in 0..rows * cols {
+            let token = match (bytes[10 + index / 4] >> (index % 4 * 2)) & 3 {
+                0 => continue,
+                1 => "X",
+                2 => "O",
+                _ => OBSTACLE,
+            };
+
+            board[index / cols][index % cols] = String::from(token);
+        }
+
+        let win_directions = if bytes[4] == 1 {
+            WinDirections::default()
+        } else {
+            WinDirections {
+                rows: options & 4 != 0,
+                cols: options & 8 != 0,
+                diagonals: options & 16 != 0,
+            }
+        };
+        let mut game = Self::from_rectangular_board(board, turn, win_length)
+            .and_then(|game| {
+                game.with_toroidal(options & 1 != 0)
+                    .with_win_directions(win_directions)
+            })
+            .map_err(|err| invalid_save(&err))?;
+
+        if options & 2 != 0 {
+            game.engine.x_side = Turn::Bot;
+        }
+
+        Ok(game)
+    }
+
+    /// Resumes the game saved at a path if there is a valid save, or else constructs a new
+    /// `Game` object. Prints which of the two happened. A resumed save is deleted, so the next
+    /// session starts a new game unless this one is saved again.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the save file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::resume_or_new(Path::new("tic_tac_toe.save"));
+    /// ```
+    pub fn resume_or_new(path: &Path) -> Game {
+        let (game, message) = Self::resume_or_new_with_message(path);
+
+        println!("{}", message);
+
+        game
+    }
+
+    /// Resumes the game saved at a path like `resume_or_new`, getting the message to print
+    /// instead of printing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the save file.
+    fn resume_or_new_with_message(path: &Path) -> (Game, String) {
+        if !path.exists() {
+            return (
+                Game::new(),
+                format!("No saved game at {}, starting a new game.", path.display()),
+            );
+        }
+
+        match Self::load(path) {
+            Ok(game) => {
+                let mut message = format!("Resumed the saved game from {}.", path.display());
+
+                if let Err(err) = fs::remove_file(path) {
+                    message.push_str(&format!(" Could not delete the save: {}", err));
+                }
+
+                (game, message)
+            }
+            Err(err) => (
+                Game::new(),
+                format!(
+                    "Could not resume the saved game from {} ({}), starting a new game.",
+                    path.display(),
+                    err
+                ),
+            ),
+        }
+    }
+
+    /// Turns board notation into a board.
+    ///
+    /// # Arguments
+    ///
+    /// * `notation` - The board notation, e.g. "X---O----".
+    fn notation_to_board(notation: &str) -> Result<Board, String> {
+        let cells: Vec<char> = notation.trim().chars().collect();
+        let size = (1..)
+            .take_while(|size| size * size <= cells.len())
+            .last()
+            .unwrap_or(0);
+
+        if size * size != cells.len() {
+            return Err(String::from(
+                "The notation must have the cells of a square board!",
+            ));
+        }
+
+        Self::check_size(size)?;
+        Self::cells_to_board(&cells, size)
+    }
+
+    /// Turns the cells of board notation into a board.
+    ///
+    /// # Arguments
+    ///
+    /// * `cells` - The cells of the notation, row by row.
+    /// * `cols` - The number of columns.
+    fn cells_to_board(cells: &[char], cols: usize) -> Result<Board, String> {
+        let mut board = Board::new();
+
+        for (index, cell) in cells.iter().enumerate() {
+            if index % cols == 0 {
+                board.push(Vec::new());
+            }
+
+            let cell = match *cell {
+                'X' | 'x' => String::from("X"),
+                'O' | 'o' => String::from("O"),
+                '#' => String::from(OBSTACLE),
+                '-' => (index + 1).to_string(),
+                other => return Err(format!("Invalid notation cell '{}'!", other)),
+            };
+
+            board[index / cols].push(cell);
+        }
+
+        Ok(board)
+    }
+
+    /// Applies a move for the current turn, then passes the turn to the other side.
+    ///
+    /// Fails if the move is illegal, or if the autosave fails after the move is applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_move` - The move to apply, an integer between 1 and the number of cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let mut game = Game::new();
+    ///
+    /// assert!(game.apply_move(5).is_ok());
+    /// assert!(game.apply_move(5).is_err());
+    /// ```
+    pub fn apply_move(&mut self, game_move: u32) -> Result<(), String> {
+        self.engine.check_move(game_move)?;
+        self.place_move(game_move);
+        self.engine.current_turn = self.engine.get_next_turn();
+
+        self.autosave()
+            .map_err(|err| format!("Failed to autosave the game: {}", err))
+    }
+
+    /// Replays moves one ply at a time, writing the board after every ply and waiting for the
+    /// user to press enter before the next one.
+    ///
+    /// # Arguments
+    ///
+    /// * `moves` - The moves to replay, in order.
+    /// * `input` - The input to wait for enter on.
+    /// * `output` - The output to write the boards to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io;
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let mut game = Game::new();
+    /// let mut input = io::Cursor::new("\n\n");
+    /// let mut output = Vec::new();
+    ///
+    /// game.replay_interactive(&[5, 1, 9], &mut input, &mut output).unwrap();
+    ///
+    /// assert_eq!(game.to_notation(), "O---X---X");
+    /// ```
+    pub fn replay_interactive<I: Input, W: Write>(
+        &mut self,
+        moves: &[u32],
+        input: &mut I,
+        output: &mut W,
+    ) -> io::Result<()> {
+        for (index, &game_move) in moves.iter().enumerate() {
+            let rendered = self
+                .replay_step(game_move)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+
+            write!(output, "{}", rendered)?;
+
+            if index + 1 < moves.len() {
+                writeln!(output, "Press enter for the next move...")?;
+                input.read_line()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Plays the moves of a file, one move per line, for the sides in turn, writing the board
+    /// after every move. Blank lines are skipped.
+    ///
+    /// Returns the winner if the moves win the game, or `None` for a draw. As with
+    /// `verify_transcript`, fails with the line number of the first line that is not a legal move
+    /// or comes after the game is over, if the moves don't finish the game, or if the file cannot
+    /// be read.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file of moves.
+    /// * `output` - The output to write the boards to.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::io;
+    /// use std::path::Path;
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let mut game = Game::new();
+    ///
+    /// game.play_from_file(Path::new("moves.txt"), &mut io::stdout()).unwrap();
+    /// ```
+    pub fn play_from_file<W: Write>(
+        &mut self,
+        path: &Path,
+        output: &mut W,
+    ) -> io::Result<Option<Turn>> {
+        let contents = fs::read_to_string(path)?;
+
+        for (index, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let rendered = line
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| String::from("Please input a valid unsigned integer!"))
+                .and_then(|game_move| {
+                    if self.engine.is_over() {
+                        Err(String::from("The move comes after the game is over!"))
+                    } else {
+                        self.replay_step(game_move)
+                    }
+                })
+                .map_err(|err| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("Line {}: {}", index + 1, err),
+                    )
+                })?;
+
+            write!(output, "{}", rendered)?;
+        }
+
+        if !self.engine.is_over() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "The game is not over after the last move!",
+            ));
+        }
+
+        Ok(self.engine.winner())
+    }
+
+    /// Applies a move of a replay, getting the board as it is printed afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_move` - The move to apply.
+    fn replay_step(&mut self, game_move: u32) -> Result<String, String> {
+        self.apply_move(game_move)?;
+
+        Ok(self.render_board())
+    }
+
+    /// Determines if the game is over, either by a win or by a full board.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("XXXOO----").unwrap();
+    ///
+    /// assert!(game.is_over());
+    /// ```
+    pub fn is_over(&self) -> bool {
+        self.engine.is_over()
+    }
+
+    /// Determines if the game is a dead draw, where every line is blocked by both sides.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("XOXXOOOX-").unwrap();
+    ///
+    /// assert!(game.is_dead_draw());
+    /// ```
+    pub fn is_dead_draw(&self) -> bool {
+        self.engine.is_dead_draw()
+    }
+
+    /// Gets every line completed by the winner, while `Engine::winner` still names the one
+    /// winning side. See `Engine::winning_lines_all`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("XXXOO----").unwrap();
+    ///
+    /// assert_eq!(game.winning_lines_all().len(), 1);
+    /// ```
+    pub fn winning_lines_all(&self) -> Vec<Vec<(usize, usize)>> {
+        self.engine.winning_lines_all()
+    }
+
+    /// Gets each winning line with a label and the contents of its cells, for debugging the
+    /// win logic. See `Engine::line_report` for the labels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("X-O-XO--X").unwrap();
+    /// let report = game.line_report();
+    ///
+    /// assert!(report.contains(&(String::from("diag main"), vec![String::from("X"); 3])));
+    /// ```
+    pub fn line_report(&self) -> Vec<(String, Vec<String>)> {
+        self.engine.line_report()
+    }
+
+    /// Gets the direction of the line that won the game, if the game is won.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Direction, Game};
+    ///
+    /// let game = Game::from_notation("XOOOX-OXX").unwrap();
+    ///
+    /// assert_eq!(game.winning_direction(), Some(Direction::DiagMain));
+    /// ```
+    pub fn winning_direction(&self) -> Option<Direction> {
+        self.engine.winning_direction()
+    }
+
+    /// Gets the game board.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new();
+    ///
+    /// assert_eq!(game.board()[1][1], "5");
+    /// ```
+    pub fn board(&self) -> &Board {
+        &self.engine.board
+    }
+
+    /// Gets the token of the side to move, e.g. to show "Your turn (X)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new();
+    ///
+    /// assert_eq!(game.current_token(), "X");
+    /// ```
+    pub fn current_token(&self) -> &str {
+        self.engine.turn_token(&self.engine.current_turn)
+    }
+
+    /// Gets an independent copy of the position and options, for searching or undoing.
+    ///
+    /// Unlike `clone`, the snapshot leaves out the session: it has an empty scoreboard, no save,
+    /// autosave, log, or score paths, no event sender or commentator, and its own random number
+    /// generator. That generator is seeded from the game's, without advancing it, so the
+    /// snapshots of a seeded game are as reproducible as the game.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("X---O----").unwrap();
+    /// let mut snapshot = game.snapshot();
+    ///
+    /// snapshot.apply_move(9).unwrap();
+    ///
+    /// assert_eq!(game.to_notation(), "X---O----");
+    /// assert_eq!(snapshot.to_notation(), "X---O---X");
+    /// ```
+    pub fn snapshot(&self) -> Game {
+        let snapshot_seed = self.rng.clone().gen::<usize>();
+
+        Game {
+            scoreboard: Scoreboard::default(),
+            config: Config {
+                seed: None,
+                interrupt_save_path: None,
+                autosave_path: None,
+                logfile_path: None,
+                score_path: None,
+                ..self.config.clone()
+            },
+            event_sender: None,
+            commentator: None,
+            rng: StdRng::from_seed(&[snapshot_seed]),
+            ..self.clone()
+        }
+    }
+
+    /// Lists the cells that differ between this board and another board snapshot.
+    ///
+    /// Each entry holds the row, the column, and the cell's content in `other`. Cells that only
+    /// differ in their move number placeholders are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The board snapshot to compare against.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let before = Game::new();
+    /// let mut after = Game::new();
+    ///
+    /// after.apply_move(5).unwrap();
+    ///
+    /// assert_eq!(before.diff(after.board()), vec![(1, 1, String::from("X"))]);
+    /// ```
+    pub fn diff(&self, other: &Board) -> Vec<(usize, usize, String)> {
+        let is_placeholder = |cell: &str| cell.parse::<u32>().is_ok();
+        let mut changes = Vec::new();
+
+        for (row, (own_row, other_row)) in self.engine.board.iter().zip(other.iter()).enumerate() {
+            for (col, (own_cell, other_cell)) in own_row.iter().zip(other_row.iter()).enumerate() {
+                if own_cell != other_cell
+                    && !(is_placeholder(own_cell) && is_placeholder(other_cell))
+                {
+                    changes.push((row, col, other_cell.clone()));
+                }
+            }
+        }
+
+        changes
+    }
+
+    /// Sets the turn of the game, for adjusting a position in tests or while debugging.
+    ///
+    /// # Arguments
+    ///
+    /// * `turn` - The turn to move to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Game, Turn};
+    ///
+    /// let mut game = Game::new();
+    ///
+    /// game.set_turn(Turn::Bot);
+    /// game.apply_move(5).unwrap();
+    ///
+    /// assert_eq!(game.to_notation(), "----O----");
+    /// ```
+    pub fn set_turn(&mut self, turn: Turn) {
+        self.engine.current_turn = turn;
+    }
+
+    /// Counts the tokens on the board, returning the number of X and O tokens in that order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("X---O---X").unwrap();
+    ///
+    /// assert_eq!(game.token_counts(), (2, 1));
+    /// ```
+    pub fn token_counts(&self) -> (usize, usize) {
+        let count = |token: &str| {
+            self.engine
+                .board
+                .iter()
+                .flatten()
+                .filter(|cell| *cell == token)
+                .count()
+        };
+
+        (count("X"), count("O"))
+    }
+
+    /// Places several tokens at once to set up a position, e.g. "X:5 O:1 X:9" to reproduce a
+    /// reported state. The placements are not moves, so the history and the side to move are
+    /// kept.
+    ///
+    /// The setup is only applied if every placement is on a distinct open cell, the token
+    /// counts are valid for the side to move (see `validate_state`), and the game is not over.
+    ///
+    /// # Arguments
+    ///
+    /// * `setup` - The placements, each a token and a cell separated by ":".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let mut game = Game::new();
+    ///
+    /// game.apply_setup("X:5 O:1").unwrap();
+    ///
+    /// assert_eq!(game.to_notation(), "O---X----");
+    /// assert!(game.apply_setup("X:9").is_err());
+    /// ```
+    pub fn apply_setup(&mut self, setup: &str) -> Result<(), String> {
+        let mut setup_game = self.clone();
+
+        for (turn, game_move) in self.parse_placements(setup)? {
+            if !setup_game.engine.is_open_move(game_move) {
+                return Err(format!("Cell {} is not open to place on!", game_move));
+            }
+
+            let (row, col) = setup_game.engine.move_to_board_location(game_move);
+
+            setup_game.engine.board[row][col] = self.engine.turn_token(&turn).to_string();
+        }
+
+        setup_game.validate_state()?;
+        setup_game.engine.assert_playable()?;
+        self.engine.board = setup_game.engine.board;
+
+        Ok(())
+    }
+
+    /// Parses the placements of a setup into the side and the cell of each placed token.
+    ///
+    /// # Arguments
+    ///
+    /// * `setup` - The placements, e.g. "X:5 O:1 X:9".
+    fn parse_placements(&self, setup: &str) -> Result<Vec<(Turn, u32)>, String> {
+        setup
+            .split_whitespace()
+            .map(|placement| {
+                let invalid = || format!("Invalid placement '{}', e.g. X:5!", placement);
+                let mut parts = placement.splitn(2, ':');
+                let token = parts.next().unwrap_or("").to_uppercase();
+                let game_move = parts
+                    .next()
+                    .and_then(|cell| cell.parse::<u32>().ok())
+                    .ok_or_else(invalid)?;
+                let turn = [Turn::Player, Turn::Bot]
+                    .iter()
+                    .cloned()
+                    .find(|turn| self.engine.turn_token(turn) == token)
+                    .ok_or_else(invalid)?;
+
+                Ok((turn, game_move))
+            })
+            .collect()
+    }
+
+    /// Determines if the position can arise from legal play, where the side to move follows the
+    /// other side's last move. Beyond the token counts checked by `validate_state`, this rules
+    /// out e.g. a board where both sides have a line, since the game ends at the first line.
+    ///
+    /// The moves are taken back one at a time, so the check grows quickly with the number of
+    /// tokens and is meant for small boards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// assert!(Game::from_notation("X---O---X").unwrap().is_reachable());
+    /// assert!(!Game::from_notation("XXXOOO---").unwrap().is_reachable());
+    /// ```
+    pub fn is_reachable(&self) -> bool {
+        self.reaching_sequences() > 0
+    }
+
+    /// Counts the legal move orders that lead to exactly the current position, with the current
+    /// side to move, or 0 if the position cannot arise from legal play. This is the retrograde
+    /// complement of `subtree_size`.
+    ///
+    /// The count saturates at `u64::MAX`, and the search grows quickly with the number of
+    /// tokens, so it is meant for small boards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("X---O---X").unwrap();
+    ///
+    /// assert_eq!(game.reaching_sequences(), 2);
+    /// ```
+    pub fn reaching_sequences(&self) -> u64 {
+        self.engine.clone().retrograde_count(&mut HashMap::new())
+    }
+
+    /// Validates that the state of the game could be reached by alternating moves.
+    ///
+    /// Either side may move first, so the token counts may differ by at most one, and a side
+    /// with more tokens than the other can't be the one to move.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Game, Turn};
+    ///
+    /// let mut game = Game::from_notation("X---O---X").unwrap();
+    ///
+    /// assert!(game.validate_state().is_ok());
+    ///
+    /// game.set_turn(Turn::Player);
+    ///
+    /// assert!(game.validate_state().is_err());
+    /// ```
+    pub fn validate_state(&self) -> Result<(), String> {
+        let (x_count, o_count) = self.token_counts();
+
+        let parity_is_valid = match self.engine.current_turn {
+            Turn::Player => x_count == o_count || x_count + 1 == o_count,
+            Turn::Bot => x_count == o_count || x_count == o_count + 1,
+        };
+
+        if parity_is_valid {
+            Ok(())
+        } else {
+            Err(format!(
+                "Invalid parity: {} X and {} O tokens with the {:?} to move!",
+                x_count, o_count, self.engine.current_turn
+            ))
+        }
+    }
+
+    /// Gets the moves that are still open on the board, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("XOXOXOXO-").unwrap();
+    ///
+    /// assert_eq!(game.available_moves(), vec![9]);
+    /// ```
+    pub fn available_moves(&self) -> Vec<u32> {
+        self.engine.available_moves()
+    }
+
+    /// Gets the number of cells that are still open on the board.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("XOXOXOXO-").unwrap();
+    ///
+    /// assert_eq!(game.remaining(), 1);
+    /// ```
+    pub fn remaining(&self) -> usize {
+        self.available_moves().len()
+    }
+
+    /// Gets the fraction of the cells that are taken, from 0.0 on an empty board to 1.0 on a
+    /// full board, e.g. to pace the messages of a UI or the effort of a bot. Obstacles are left
+    /// out, since they are never played, and a board of only obstacles counts as full.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("XO-------").unwrap();
+    ///
+    /// assert!((game.fill_ratio() - 2.0 / 9.0).abs() < 1e-9);
+    /// ```
+    pub fn fill_ratio(&self) -> f64 {
+        let playable = self.engine.cell_count() as usize - self.config.obstacles.len();
+
+        if playable == 0 {
+            return 1.0;
+        }
+
+        (playable - self.remaining()) as f64 / playable as f64
+    }
+
+    /// Ranks the legal moves from best to worst for the side to move, using minimax.
+    ///
+    /// Wins come first, then draws, then losses. Wins are tiebroken by the fewest plies to win,
+    /// and losses by the most plies to lose. A finished game has no moves to rank, and neither
+    /// does a position with more than nine open cells, which is too large to search. If symmetric
+    /// moves are collapsed, only the first of the moves leading to symmetric positions is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Game, Outcome};
+    ///
+    /// let game = Game::from_notation("XX-OO----").unwrap();
+    ///
+    /// assert_eq!(game.ranked_moves()[0], (3, Outcome::Win));
+    /// assert!(Game::new().with_size(4).unwrap().ranked_moves().is_empty());
+    /// ```
+    pub fn ranked_moves(&self) -> Vec<(u32, Outcome)> {
+        let mut search = self.snapshot();
+        let mut positions = HashSet::new();
+
+        self.ranked_moves_with_plies()
+            .into_iter()
+            .filter(|&(game_move, _, _)| {
+                if !self.config.collapse_symmetric {
+                    return true;
+                }
+
+                search.engine.make_move(game_move);
+
+                let position = search.canonical_notation();
+
+                search.engine.unmake_move(game_move);
+
+                positions.insert(position)
+            })
+            .map(|(game_move, outcome, _)| (game_move, outcome))
+            .collect()
+    }
+
+    /// Gets the canonical form of the board, the same for every rotation and reflection of it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let corner = Game::from_notation("X--------").unwrap();
+    /// let other_corner = Game::from_notation("--------X").unwrap();
+    ///
+    /// assert_eq!(corner.canonical_board(), other_corner.canonical_board());
+    /// ```
+    pub fn canonical_board(&self) -> Board {
+        let cells: Vec<String> = self
+            .canonical_notation()
+            .chars()
+            .enumerate()
+            .map(|(index, cell)| match cell {
+                '-' => (index + 1).to_string(),
+                token => token.to_string(),
+            })
+            .collect();
+
+        cells
+            .chunks(self.engine.cols())
+            .map(|row| row.to_vec())
+            .collect()
+    }
+
+    /// Determines if two games are in the same position, i.e. they have the same tokens in the
+    /// same cells and the same side to move. The options of the games are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The game to compare with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Difficulty, Game};
+    ///
+    /// let mut game = Game::new().with_difficulty(Difficulty::Hard);
+    ///
+    /// game.apply_move(1).unwrap();
+    ///
+    /// assert!(game.position_eq(&Game::from_notation("X--------").unwrap()));
+    /// ```
+    pub fn position_eq(&self, other: &Game) -> bool {
+        self.engine.current_turn == other.engine.current_turn
+            && self.engine.board.len() == other.engine.board.len()
+            && self.to_notation() == other.to_notation()
+    }
+
+    /// Determines if two games are in the same position like `position_eq`, but up to the
+    /// rotations and reflections of the board, by comparing their `canonical_board`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The game to compare with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let corner = Game::from_notation("X--------").unwrap();
+    /// let other_corner = Game::from_notation("--------X").unwrap();
+    ///
+    /// assert!(!corner.position_eq(&other_corner));
+    /// assert!(corner.position_eq_symmetric(&other_corner));
+    /// ```
+    pub fn position_eq_symmetric(&self, other: &Game) -> bool {
+        self.engine.current_turn == other.engine.current_turn
+            && self.engine.board.len() == other.engine.board.len()
+            && self.canonical_board() == other.canonical_board()
+    }
+
+    /// Gets the name of the opening played so far, if the moves are a named opening of the
+    /// default board, up to its rotations and reflections.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let mut game = Game::new();
+    ///
+    /// game.apply_move(9).unwrap();
+    ///
+    /// assert_eq!(game.opening_name(), Some("Corner Game"));
+    /// ```
+    pub fn opening_name(&self) -> Option<&'static str> {
+        let is_default_board = self.engine.rows() == 3
+            && self.engine.cols() == 3
+            && self.engine.win_length == 3
+            && self.history.len() + self.remaining() == 9;
+
+        if !is_default_board {
+            return None;
+        }
+
+        let position = Self::opening_notation(&self.history);
+
+        OPENING_BOOK
+            .iter()
+            .find(|&&(moves, _)| {
+                moves.len() == self.history.len() && Self::opening_notation(moves) == position
+            })
+            .map(|&(_, name)| name)
+    }
+
+    /// Gets the canonical notation of the position reached by moves on the default board, with X
+    /// moving first.
+    ///
+    /// # Arguments
+    ///
+    /// * `moves` - The moves played, in order.
+    fn opening_notation(moves: &[u32]) -> String {
+        let mut game = Game::new();
+
+        for &game_move in moves {
+            game.engine.make_move(game_move);
+        }
+
+        game.canonical_notation()
+    }
+
+    /// Gets the board notation of the canonical form of the board, the least notation among
+    /// every rotation and reflection of the board.
+    ///
+    /// A rectangular board only has the symmetries that keep its shape, i.e. no quarter turns or
+    /// transposes.
+    fn canonical_notation(&self) -> String {
+        let last_row = self.engine.rows() - 1;
+        let last_col = self.engine.cols() - 1;
+        let symmetries: [&dyn Fn(usize, usize) -> (usize, usize); 8] = [
+            &|row, col| (row, col),
+            &|row, col| (last_row - row, last_col - col),
+            &|row, col| (row, last_col - col),
+            &|row, col| (last_row - row, col),
+            &|row, col| (col, last_row - row),
+            &|row, col| (last_col - col, row),
+            &|row, col| (col, row),
+            &|row, col| (last_col - col, last_row - row),
+        ];
+        let shape_symmetries = if last_row == last_col { 8 } else { 4 };
+
+        symmetries
+            .iter()
+            .take(shape_symmetries)
+            .map(|symmetry| {
+                (0..=last_row)
+                    .flat_map(|row| (0..=last_col).map(move |col| (row, col)))
+                    .map(|(row, col)| {
+                        let (source_row, source_col) = symmetry(row, col);
+
+                        match self.engine.board[source_row][source_col].as_str() {
+                            "X" => 'X',
+                            "O" => 'O',
+                            OBSTACLE => '#',
+                            _ => '-',
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .min()
+            .unwrap()
+    }
+
+    /// Gets a move that instantly wins the game for the side to move, if there is one.
+    ///
+    /// Unlike `forced_wins`, this does not search, so it is cheap enough to call on every turn.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("XX-OO----").unwrap();
+    ///
+    /// assert_eq!(game.immediate_win(), Some(3));
+    /// ```
+    pub fn immediate_win(&self) -> Option<u32> {
+        self.engine.winning_move(self.engine.current_turn)
+    }
+
+    /// Counts the open threats of a side, the lines that are one token short of a win for the
+    /// side with the last cell empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - The side to count the open threats of.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Game, Turn};
+    ///
+    /// let game = Game::from_notation("XX-OO----").unwrap();
+    ///
+    /// assert_eq!(game.near_wins(Turn::Player), 1);
+    /// assert_eq!(game.near_wins(Turn::Bot), 1);
+    /// ```
+    pub fn near_wins(&self, side: Turn) -> usize {
+        let token = self.engine.turn_token(&side);
+        let other_token = match side {
+            Turn::Player => self.engine.turn_token(&Turn::Bot),
+            Turn::Bot => self.engine.turn_token(&Turn::Player),
+        };
+
+        self.engine
+            .win_lines
+            .iter()
+            .filter(|line| {
+                let tokens = line
+                    .iter()
+                    .filter(|&&(row, col)| self.engine.board[row][col] == token)
+                    .count();
+                let blocked = line
+                    .iter()
+                    .any(|&(row, col)| self.engine.board[row][col] == other_token);
+
+                tokens + 1 == line.len() && !blocked
+            })
+            .count()
+    }
+
+    /// Scores how much of the board each side controls, a heuristic for analysis.
+    ///
+    /// Each line scores +1 if only the player can still complete it, -1 if only the bot can, and
+    /// 0 if it is blocked by both sides or empty. The score is the sum over all lines. It is also
+    /// how the depth-limited search of `with_search_budget` scores the positions at its horizon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("X---O---X").unwrap();
+    ///
+    /// assert_eq!(game.control_score(), 1);
+    /// ```
+    pub fn control_score(&self) -> i32 {
+        self.engine.control_score()
+    }
+
+    /// Scores who is ahead, from -1.0 (the bot) to 1.0 (the player), e.g. for an evaluation bar.
+    ///
+    /// A finished game scores 1.0, -1.0, or 0.0 by its result. A forced win scores from 1.0 for a
+    /// win on the next move down to 0.5 for the slowest win, and a forced loss the same for the
+    /// bot. A drawn position, or one with too many open cells to search, scores half of the
+    /// `control_score` per line, so it stays between -0.5 and 0.5.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// assert_eq!(Game::from_notation("XX-OO----").unwrap().advantage(), 1.0);
+    /// assert_eq!(Game::new().advantage(), 0.0);
+    /// ```
+    pub fn advantage(&self) -> f64 {
+        if self.is_over() {
+            return match self.engine.winner() {
+                Some(Turn::Player) => 1.0,
+                Some(Turn::Bot) => -1.0,
+                None => 0.0,
+            };
+        }
+
+        if let Some((outcome, plies)) = self.outcome_with_distance() {
+            let outcome = match self.engine.current_turn {
+                Turn::Player => outcome,
+                Turn::Bot => outcome.flip(),
+            };
+            let decisive = 1.0 - (plies - 1) as f64 / (2 * self.remaining()) as f64;
+
+            match outcome {
+                Outcome::Win => return decisive,
+                Outcome::Loss => return -decisive,
+                Outcome::Draw => {}
+            }
+        }
+
+        match self.engine.win_lines.len() {
+            0 => 0.0,
+            lines => self.control_score() as f64 / (2 * lines) as f64,
+        }
+    }
+
+    /// Gets the legal moves that would give a side a fork, two or more open threats at once, so
+    /// the other side cannot block them all.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - The side to find the forking moves of.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Game, Turn};
+    ///
+    /// let game = Game::from_notation("XO--O---X").unwrap();
+    ///
+    /// assert_eq!(game.fork_moves(Turn::Player), vec![7]);
+    /// ```
+    pub fn fork_moves(&self, side: Turn) -> Vec<u32> {
+        let token = self.engine.turn_token(&side).to_string();
+        let mut search = self.clone();
+
+        self.available_moves()
+            .into_iter()
+            .filter(|&game_move| {
+                let (row, col) = self.engine.move_to_board_location(game_move);
+
+                search.engine.board[row][col] = token.clone();
+
+                let forks = search.near_wins(side) >= 2;
+
+                search.engine.board[row][col] = game_move.to_string();
+                forks
+            })
+            .collect()
+    }
+
+    /// Lists the legal moves with the `control_score` of the position after each, best for the
+    /// side to move first, i.e. the highest scores first for the player and the lowest first for
+    /// the bot. Equally scored moves keep their order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// assert_eq!(Game::new().moves_by_control()[0], (5, 4));
+    /// ```
+    pub fn moves_by_control(&self) -> Vec<(u32, i32)> {
+        let token = self.current_token().to_string();
+        let mut search = self.clone();
+        let mut moves: Vec<(u32, i32)> = self
+            .available_moves()
+            .into_iter()
+            .map(|game_move| {
+                let (row, col) = self.engine.move_to_board_location(game_move);
+
+                search.engine.board[row][col] = token.clone();
+
+                let score = search.control_score();
+
+                search.engine.board[row][col] = game_move.to_string();
+                (game_move, score)
+            })
+            .collect();
+
+        match self.engine.current_turn {
+            Turn::Player => moves.sort_by_key(|&(_, score)| -score),
+            Turn::Bot => moves.sort_by_key(|&(_, score)| score),
+        }
+
+        moves
+    }
+
+    /// Gets the near-win counts of both sides reported at the end of a game.
+    ///
+    /// # Arguments
+    ///
+    /// * `won` - Whether the player won the game.
+    fn near_wins_summary(&self, won: bool) -> String {
+        let player_near_wins = self.near_wins(Turn::Player);
+        let summary = format!(
+            "Near wins - {}: {}, {}: {}",
+            self.engine.turn_token(&Turn::Player),
+            player_near_wins,
+            self.engine.turn_token(&Turn::Bot),
+            self.near_wins(Turn::Bot)
+        );
+
+        if !won && !self.config.two_player && player_near_wins > 0 {
+            format!("You were close! {}", summary)
+        } else {
+            summary
+        }
+    }
+
+    /// Gets the moves that force a win for the side to move, in ascending order. There are none
+    /// while more than nine cells are open, since the position is too large to search.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("XX-OO----").unwrap();
+    ///
+    /// assert_eq!(game.forced_wins(), vec![3]);
+    /// ```
+    pub fn forced_wins(&self) -> Vec<u32> {
+        let mut wins: Vec<u32> = self
+            .ranked_moves_with_plies()
+            .into_iter()
+            .filter(|&(_, outcome, _)| outcome == Outcome::Win)
+            .map(|(game_move, _, _)| game_move)
+            .collect();
+
+        wins.sort();
+
+        wins
+    }
+
+    /// Gets the size of the game tree from the current position, i.e. the number of positions
+    /// reached by every legal play sequence.
+    ///
+    /// Every sequence prefix counts as a node, so the current position and each terminal
+    /// position are counted too. By this convention, the default empty board has 549946 nodes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("XOXOXO---").unwrap();
+    ///
+    /// assert_eq!(game.subtree_size(), 8);
+    /// ```
+    pub fn subtree_size(&self) -> u64 {
+        count_nodes_from(&mut self.engine.clone())
+    }
+
+    /// Exports the game tree from the current position as a Graphviz DOT digraph, e.g. to render
+    /// with `dot -Tpng`.
+    ///
+    /// The nodes are labeled by their board notation and the edges by their move. The tree stops
+    /// at finished games and at the maximum depth.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_depth` - The number of plies to export below the current position.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("XOXOXO---").unwrap();
+    /// let dot = game.export_decision_tree(1);
+    ///
+    /// assert!(dot.contains("n0 -> n1 [label=\"7\"];"));
+    /// ```
+    pub fn export_decision_tree(&self, max_depth: usize) -> String {
+        let mut search = self.snapshot();
+        let mut dot = String::from("digraph decision_tree {\n");
+
+        search.write_decision_tree(max_depth, &mut 0, &mut dot);
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    /// Writes the nodes and edges of the game tree from the current position in DOT, restoring
+    /// the position once written.
+    ///
+    /// Returns the id of the node of the current position.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth_left` - The number of plies still to write below the current position.
+    /// * `nodes` - The number of nodes written so far, which gives the next node its id.
+    /// * `dot` - The DOT to write to.
+    fn write_decision_tree(
+        &mut self,
+        depth_left: usize,
+        nodes: &mut usize,
+        dot: &mut String,
+    ) -> usize {
+        let id = *nodes;
+
+        *nodes += 1;
+        dot.push_str(&format!(
+            "    n{} [label=\"{}\"];\n",
+            id,
+            self.to_notation()
+        ));
+
+        if depth_left == 0 || self.is_over() {
+            return id;
+        }
+
+        for game_move in self.available_moves() {
+            self.engine.make_move(game_move);
+
+            let child = self.write_decision_tree(depth_left - 1, nodes, dot);
+
+            self.engine.unmake_move(game_move);
+            dot.push_str(&format!(
+                "    n{} -> n{} [label=\"{}\"];\n",
+                id, child, game_move
+            ));
+        }
+
+        id
+    }
+
+    /// Generates a puzzle, a mid-game position where the side to move has a forced win.
+    ///
+    /// The position is reached by random legal play, and the difficulty sets how many plies the
+    /// quickest forced win takes. The same seed and difficulty always generate the same puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed for the random play.
+    /// * `difficulty` - The difficulty of the puzzle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Difficulty, Game};
+    ///
+    /// let puzzle = Game::generate_puzzle(42, Difficulty::Medium);
+    ///
+    /// assert!(!puzzle.forced_wins().is_empty());
+    /// ```
+    pub fn generate_puzzle(seed: u64, difficulty: Difficulty) -> Game {
+        let mut rng = StdRng::from_seed(&[seed as usize]);
+
+        loop {
+            let mut puzzle = Game::new();
+            let plies = rng.gen_range(2, 7);
+
+            while puzzle.history.len() < plies && !puzzle.is_over() {
+                let game_move = *rng.choose(&puzzle.available_moves()).unwrap();
+
+                puzzle.apply_move(game_move).unwrap();
+            }
+
+            if puzzle.is_over() {
+                continue;
+            }
+
+            match puzzle.ranked_moves_with_plies().first() {
+                Some(&(_, Outcome::Win, plies)) if plies == difficulty.plies_to_win() => {
+                    return puzzle
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Generates a fair starting position, with one or two tokens of each side placed at random,
+    /// the player to move, and a draw under best play, so neither side starts ahead. The tokens
+    /// are not part of the history. The same seed always generates the same position.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed for the random placement.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let start = Game::random_fair_start(42);
+    ///
+    /// assert!(start.can_hold());
+    /// ```
+    pub fn random_fair_start(seed: u64) -> Game {
+        let mut rng = StdRng::from_seed(&[seed as usize]);
+
+        loop {
+            let mut start = Game::new();
+            let tokens = rng.gen_range(1, 3) * 2;
+
+            for _ in 0..tokens {
+                let game_move = *rng.choose(&start.available_moves()).unwrap();
+
+                start.engine.make_move(game_move);
+            }
+
+            if start.is_over() {
+                continue;
+            }
+
+            if let Some(&(_, Outcome::Draw, _)) = start.ranked_moves_with_plies().first() {
+                return start;
+            }
+        }
+    }
+
+    /// Generates an endgame to practice, a legal position with only a few open cells left and
+    /// the player to move.
+    ///
+    /// The position is reached by random legal play that does not win the game. The same seed and
+    /// number of open cells always generate the same endgame.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed for the random play.
+    /// * `empty_cells` - The number of open cells in the endgame, odd and at most 9, since the
+    ///   player moves when both sides have played the same number of tokens.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let endgame = Game::practice_endgame(42, 3).unwrap();
+    ///
+    /// assert_eq!(endgame.available_moves().len(), 3);
+    /// assert!(Game::practice_endgame(42, 4).is_err());
+    /// ```
+    pub fn practice_endgame(seed: u64, empty_cells: usize) -> Result<Game, String> {
+        let cells = Game::new().engine.cell_count() as usize;
+
+        if empty_cells.is_multiple_of(2) || empty_cells > cells {
+            return Err(format!(
+                "The number of open cells must be odd and at most {}!",
+                cells
+            ));
+        }
+
+        let mut rng = StdRng::from_seed(&[seed as usize]);
+
+        loop {
+            let mut endgame = Game::new();
+
+            while endgame.history.len() < cells - empty_cells && !endgame.is_over() {
+                let game_move = *rng.choose(&endgame.available_moves()).unwrap();
+
+                endgame.apply_move(game_move).unwrap();
+            }
+
+            if !endgame.is_over() {
+                return Ok(endgame);
+            }
+        }
+    }
+
+    /// Suggests the best move for the side to move, along with the outcome it leads to, or
+    /// `None` if the game is already over or more than nine cells are open to search.
+    ///
+    /// In a lost position, the suggested move is the one that delays the loss the longest.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Game, Outcome};
+    ///
+    /// let game = Game::from_notation("XX-OO----").unwrap();
+    ///
+    /// assert_eq!(game.hint_with_confidence(), Some((3, Outcome::Win)));
+    /// assert_eq!(Game::from_notation("XXXOO----").unwrap().hint_with_confidence(), None);
+    /// ```
+    pub fn hint_with_confidence(&self) -> Option<(u32, Outcome)> {
+        self.ranked_moves().first().cloned()
+    }
+
+    /// Gets the move that resists the longest for the side to move, or `None` if the game is
+    /// already over or too large to search, with more than nine open cells.
+    ///
+    /// A winning or drawing move is preferred if there is one. Otherwise, among the losing moves,
+    /// the move that takes the most plies to lose is chosen.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("XO--X----").unwrap();
+    ///
+    /// assert_eq!(game.best_defensive_move(), Some(9));
+    /// ```
+    pub fn best_defensive_move(&self) -> Option<u32> {
+        self.ranked_moves_with_plies()
+            .first()
+            .map(|&(game_move, _, _)| game_move)
+    }
+
+    /// Determines if the side to move can avoid losing, i.e. it has a move that secures at least
+    /// a draw against best play. A finished game can only be held if it is drawn, and a position
+    /// with more than nine open cells, too many to search, is assumed to be held.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// assert!(Game::new().can_hold());
+    /// assert!(!Game::from_notation("XO--X----").unwrap().can_hold());
+    /// ```
+    pub fn can_hold(&self) -> bool {
+        match self.ranked_moves_with_plies().first() {
+            Some(&(_, outcome, _)) => outcome != Outcome::Loss,
+            None => !self.engine.game_is_won(),
+        }
+    }
+
+    /// Gets the legal moves that keep the outcome of the position for the side to move, i.e. the
+    /// moves that don't blunder a win into a draw or a loss, or a draw into a loss. In a lost
+    /// position, every move is safe. The moves are in ascending order, and empty when more than
+    /// nine cells are open, since the position cannot be searched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("XX-OO----").unwrap();
+    ///
+    /// assert_eq!(game.safe_moves(), vec![3]);
+    /// ```
+    pub fn safe_moves(&self) -> Vec<u32> {
+        let ranked = self.ranked_moves_with_plies();
+        let best = match ranked.first() {
+            Some(&(_, outcome, _)) => outcome,
+            None => return Vec::new(),
+        };
+        let mut safe: Vec<u32> = ranked
+            .into_iter()
+            .filter(|&(_, outcome, _)| outcome == best)
+            .map(|(game_move, _, _)| game_move)
+            .collect();
+
+        safe.sort();
+        safe
+    }
+
+    /// Gets the outcome for the side to move under optimal play, along with the number of plies
+    /// until the game ends, e.g. to show "Forced win in 3 moves.", or `None` if the game is
+    /// already over or has more than nine open cells to search.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Game, Outcome};
+    ///
+    /// let game = Game::from_notation("XX-OO----").unwrap();
+    ///
+    /// assert_eq!(game.outcome_with_distance(), Some((Outcome::Win, 1)));
+    /// ```
+    pub fn outcome_with_distance(&self) -> Option<(Outcome, u32)> {
+        self.ranked_moves_with_plies()
+            .first()
+            .map(|&(_, outcome, plies)| (outcome, plies))
+    }
+
+    /// Gets the quickest forced win for the side to move, as the move and the number of plies
+    /// until the win, or `None` if there is no forced win, the game is already over, or the
+    /// position is too large to search, with more than nine open cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::from_notation("XOOX-----").unwrap();
+    ///
+    /// assert_eq!(game.fastest_win(), Some((7, 1)));
+    /// ```
+    pub fn fastest_win(&self) -> Option<(u32, u32)> {
+        match self.ranked_moves_with_plies().first() {
+            Some(&(game_move, Outcome::Win, plies)) => Some((game_move, plies)),
+            _ => None,
+        }
+    }
+
+    /// Plays the game.
+    ///
+    /// The starting turn alternates after each play. When the session ends, the final score is
+    /// printed, even if the session is interrupted (Ctrl-C).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let mut game = Game::new();
+    ///
+    /// game.play_game();
+    /// ```
+    pub fn play_game(&mut self) {
+        match self.play_rounds(&mut terminal::Stdin::new(), &mut io::stdout()) {
+            Ok(()) => println!("{}", self.session_summary()),
+            Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {
+                println!("{}", self.on_interrupt())
+            }
+            Err(err) => println!("{}\n{}", err, self.session_summary()),
+        }
+    }
+
+    /// Gets the scoreboard of the session.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new();
+    ///
+    /// assert_eq!(game.scoreboard().wins, 0);
+    /// ```
+    pub fn scoreboard(&self) -> &Scoreboard {
+        &self.scoreboard
+    }
+
+    /// Gets the active configuration of the game, as set by the builder methods.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::{Difficulty, Game};
+    ///
+    /// let game = Game::new().with_difficulty(Difficulty::Hard);
+    ///
+    /// assert_eq!(game.config().difficulty, Difficulty::Hard);
+    /// assert_eq!(game.config().rows, 3);
+    /// ```
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Gets the decision behind the bot's most recent move, with every candidate move it
+    /// considered, or `None` if the bot hasn't moved yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new();
+    ///
+    /// assert!(game.last_decision().is_none());
+    /// ```
+    pub fn last_decision(&self) -> Option<&Decision> {
+        self.last_decision.as_ref()
+    }
+
+    /// Gets the moves the bot played in the session, in order, e.g. to script a re-run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new();
+    ///
+    /// assert!(game.bot_moves().is_empty());
+    /// ```
+    pub fn bot_moves(&self) -> &[u32] {
+        &self.bot_moves
+    }
+
+    /// Plays rounds until the player is finished playing.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to read the player's answers from.
+    /// * `output` - The output to write the game to.
+    fn play_rounds<I: Input, W: Write>(&mut self, input: &mut I, output: &mut W) -> io::Result<()> {
+        let mut finished = false;
+        let mut rounds = 0;
+
+        while !finished {
+            let turn_end = self.play_turn(input, output)?;
+
+            if turn_end != TurnEnd::Moved || self.is_over() {
+                self.render_to(output)?;
+
+                let result = if turn_end == TurnEnd::TimedOut {
+                    let side = self.engine.current_turn;
+
+                    self.resolve_forfeit(side, output)?
+                } else if turn_end == TurnEnd::Resigned {
+                    writeln!(output, "You resigned!")?;
+                    self.scoreboard.losses += 1;
+                    "loss"
+                } else if turn_end == TurnEnd::DrawAgreed || !self.engine.game_is_won() {
+                    writeln!(output, "It's a draw!")?;
+                    self.scoreboard.draws += 1;
+                    "draw"
+                } else if self.engine.current_turn == Turn::Player {
+                    if self.config.two_player {
+                        writeln!(output, "{} won!", self.engine.turn_token(&Turn::Player))?;
+                    } else {
+                        writeln!(output, "You won!")?;
+                    }
+                    self.scoreboard.wins += 1;
+                    "win"
+                } else {
+                    if self.config.two_player {
+                        writeln!(output, "{} won!", self.engine.turn_token(&Turn::Bot))?;
+                    } else {
+                        writeln!(output, "You lost!")?;
+                    }
+                    self.scoreboard.losses += 1;
+                    "loss"
+                };
+
+                self.emit(match result {
+                    "win" => GameEvent::RoundWon(Turn::Player),
+                    "loss" => GameEvent::RoundWon(Turn::Bot),
+                    _ => GameEvent::Draw,
+                });
+                self.emit(GameEvent::ScoreUpdated(self.scoreboard.clone()));
+
+                writeln!(output, "{}", self.near_wins_summary(result == "win"))?;
+
+                if self.config.hold_result {
+                    Self::wait_for_acknowledgement(input, output)?;
+                }
+
+                self.log_game(result)?;
+                self.save_score()?;
+                self.reset();
+
+                rounds += 1;
+                finished = match self.config.round_limit {
+                    Some(limit) if rounds >= limit => true,
+                    _ if self.config.auto_continue => false,
+                    _ => Self::player_is_finished(input, output)?,
+                };
+
+                if self.config.handicap_stone.is_some() {
+                    // The bot always answers the handicap stone first.
+                    continue;
+                }
+            }
+
+            self.engine.current_turn = self.engine.get_next_turn();
+            self.autosave()?;
+        }
+
+        Ok(())
+    }
+
+    /// Saves the game to the autosave file, if one is set, by writing a temporary file next to
+    /// it and renaming it over the autosave file.
+    fn autosave(&self) -> io::Result<()> {
+        let path = match self.config.autosave_path {
+            Some(ref path) => path,
+            None => return Ok(()),
+        };
+        let mut temp_path = path.clone().into_os_string();
+
+        temp_path.push(".tmp");
+        self.save(Path::new(&temp_path))?;
+
+        fs::rename(&temp_path, path)
+    }
+
+    /// Resolves the round for a side that ran out of time, counting it as a win for the other
+    /// side.
+    ///
+    /// Returns the result of the round for the player.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - The side that ran out of time.
+    /// * `output` - The output to write the result to.
+    fn resolve_forfeit<W: Write>(
+        &mut self,
+        side: Turn,
+        output: &mut W,
+    ) -> io::Result<&'static str> {
+        if self.config.two_player {
+            writeln!(
+                output,
+                "Time is up, {} forfeits the round!",
+                self.engine.turn_token(&side)
+            )?;
+        } else if side == Turn::Player {
+            writeln!(output, "Time is up, you forfeit the round!")?;
+        } else {
+            writeln!(output, "Time is up, the bot forfeits the round!")?;
+        }
+
+        if side == Turn::Player {
+            self.scoreboard.losses += 1;
+            Ok("loss")
+        } else {
+            self.scoreboard.wins += 1;
+            Ok("win")
+        }
+    }
+
+    /// Gets the time a side has to make a move, if the moves are timed.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - The side to get the time of.
+    fn time_control(&self, side: Turn) -> Option<Duration> {
+        self.config.time_controls.map(|(player, bot)| match side {
+            Turn::Player => player,
+            Turn::Bot => bot,
+        })
+    }
+
+    /// Sends an event to the spectators, if there is an event sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event to send.
+    fn emit(&self, event: GameEvent) {
+        if let Some(ref sender) = self.event_sender {
+            // A hung up receiver only means nobody is watching anymore.
+            let _ = sender.send(event);
+        }
+    }
+
+    /// Appends the completed game to the log file, if one is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The result of the game for the player.
+    fn log_game(&self, result: &str) -> io::Result<()> {
+        let path = match self.config.logfile_path {
+            Some(ref path) => path,
+            None => return Ok(()),
+        };
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or(0);
+        let mut logfile = OpenOptions::new().create(true).append(true).open(path)?;
+
+        writeln!(logfile, "{} {} {}", self.to_notation(), result, timestamp)
+    }
+
+    /// Saves the scoreboard to the score file, if one is set.
+    fn save_score(&self) -> io::Result<()> {
+        match self.config.score_path {
+            Some(ref path) => fs::write(
+                path,
+                format!(
+                    "{} {} {}\n",
+                    self.scoreboard.wins, self.scoreboard.losses, self.scoreboard.draws
+                ),
+            ),
+            None => Ok(()),
+        }
+    }
+
+    /// Parses a scoreboard saved with `save_score`.
+    ///
+    /// # Arguments
+    ///
+    /// * `contents` - The contents of the score file.
+    fn parse_score(contents: &str) -> Result<Scoreboard, String> {
+        let counts = contents
+            .split_whitespace()
+            .map(|count| count.parse::<u32>())
+            .collect::<Result<Vec<u32>, _>>()
+            .ok()
+            .filter(|counts| counts.len() == 3)
+            .ok_or_else(|| String::from("The score file is corrupt!"))?;
+
+        Ok(Scoreboard {
+            wins: counts[0],
+            losses: counts[1],
+            draws: counts[2],
+        })
+    }
+
+    /// Gets the summary printed at the end of a session, with the seed if the session was seeded
+    /// so its games can be reproduced.
+    fn session_summary(&self) -> String {
+        let summary = match self.config.seed {
+            Some(seed) => format!("Final score - {}\nSession seed: {}", self.scoreboard, seed),
+            None => format!("Final score - {}", self.scoreboard),
+        };
+
+        if self.config.speed_leaderboard && !self.move_times.is_empty() {
+            format!("{}\n{}", summary, self.speed_leaderboard_summary())
+        } else {
+            summary
+        }
+    }
+
+    /// Gets the speed leaderboard of the session, ranking the human players from the fastest
+    /// average move time to the slowest.
+    fn speed_leaderboard_summary(&self) -> String {
+        let sides = if self.config.two_player {
+            vec![Turn::Player, Turn::Bot]
+        } else {
+            vec![Turn::Player]
+        };
+        let mut entries: Vec<(String, Duration, Duration)> = sides
+            .into_iter()
+            .filter_map(|side| {
+                let times: Vec<Duration> = self
+                    .move_times
+                    .iter()
+                    .filter(|&&(turn, _)| turn == side)
+                    .map(|&(_, time)| time)
+                    .collect();
+                let name = if self.config.two_player {
+                    self.engine.turn_token(&side).to_string()
+                } else {
+                    String::from("You")
+                };
+
+                Self::timing_stats(&times).map(|(fastest, average)| (name, fastest, average))
+            })
+            .collect();
+
+        entries.sort_by_key(|&(_, _, average)| average);
+
+        let mut summary = String::from("Speed leaderboard:");
+
+        for (rank, (name, fastest, average)) in entries.into_iter().enumerate() {
+            summary.push_str(&format!(
+                "\n  {}. {} - fastest {}ms, average {}ms",
+                rank + 1,
+                name,
+                fastest.as_millis(),
+                average.as_millis()
+            ));
+        }
+
+        summary
+    }
+
+    /// Gets the fastest and the average of move times, if there are any.
+    ///
+    /// # Arguments
+    ///
+    /// * `times` - The move times.
+    fn timing_stats(times: &[Duration]) -> Option<(Duration, Duration)> {
+        let fastest = *times.iter().min()?;
+        let average = times.iter().sum::<Duration>() / times.len() as u32;
+
+        Some((fastest, average))
+    }
+
+    /// Wraps up an interrupted session, saving the in-progress game if a save path is set.
+    ///
+    /// Returns the summary to print before exiting.
+    fn on_interrupt(&self) -> String {
+        let mut summary = format!("\nInterrupted! {}", self.session_summary());
+
+        if let Some(ref path) = self.config.interrupt_save_path {
+            match self.save(path) {
+                Ok(()) => summary.push_str(&format!("\nGame saved to {}", path.display())),
+                Err(err) => summary.push_str(&format!("\nFailed to save game: {}", err)),
+            }
+        }
+
+        summary
+    }
+
+    /// Plays the "X always center-opens" training drill.
+    ///
+    /// X opens in the center and the player must find the best replies as O. After each reply,
+    /// the player is told whether it was optimal, and X answers with its own best move.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// Game::drill_center_open();
+    /// ```
+    pub fn drill_center_open() {
+        let mut input = terminal::Stdin::new();
+        let mut output = io::stdout();
+        let mut finished = false;
+
+        while !finished {
+            let mut drill = Game::from_notation("----X----").unwrap();
+
+            println!("\nX opens in the center, find the best replies as O!");
+
+            while !drill.is_over() {
+                if drill.print_board().is_err() {
+                    return;
+                }
+
+                let reply = match drill.get_player_move(&mut input, &mut output) {
+                    Ok(Some(reply)) => reply,
+                    _ => return,
+                };
+
+                println!("{}", drill.drill_feedback(reply));
+
+                drill.place_move(reply);
+                drill.engine.current_turn = drill.engine.get_next_turn();
+
+                if !drill.is_over() {
+                    let (opening_move, _) = drill.ranked_moves()[0];
+
+                    println!("X played at: {}", opening_move);
+
+                    drill.place_move(opening_move);
+                    drill.engine.current_turn = drill.engine.get_next_turn();
+                }
+            }
+
+            if drill.print_board().is_err() {
+                return;
+            }
+
+            finished = Self::player_is_finished(&mut input, &mut output).unwrap_or(true);
+        }
+    }
+
+    /// Gets the drill feedback for a reply, judging with minimax whether it was optimal.
+    ///
+    /// The reply is looked up among every move, since with `collapse_symmetric` on, the ranked
+    /// moves leave out the symmetric ones. The drill board is small enough to always be searched.
+    ///
+    /// # Arguments
+    ///
+    /// * `reply` - A reply that has already been validated.
+    fn drill_feedback(&self, reply: u32) -> String {
+        let ranked = self.ranked_moves_with_plies();
+        let (best_move, best_outcome, _) = ranked[0];
+        let reply_outcome = ranked
+            .iter()
+            .find(|&&(game_move, _, _)| game_move == reply)
+            .map(|&(_, outcome, _)| outcome)
+            .unwrap();
+
+        if reply_outcome == best_outcome {
+            format!("Correct! {} keeps the {}.", reply, best_outcome)
+        } else {
+            format!(
+                "Not optimal! {} leads to a {}, but {} leads to a {}.",
+                reply, reply_outcome, best_move, best_outcome
+            )
+        }
+    }
+
+    /// Plays a turn of the game, getting moves from the player or bot, or from the second player
+    /// in two-player mode.
+    ///
+    /// Returns how the turn ended, with a move, an agreed draw, or a resignation under the mercy
+    /// rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to read the player's move from.
+    /// * `output` - The output to write the game to.
+    fn play_turn<I: Input, W: Write>(
+        &mut self,
+        input: &mut I,
+        output: &mut W,
+    ) -> io::Result<TurnEnd> {
+        self.assign_tokens();
+        self.render_to(output)?;
+
+        let offers_mercy = self.config.mercy_rule
+            && !self.mercy_declined
+            && !self.config.two_player
+            && self.engine.current_turn == Turn::Player
+            && self.position_is_lost();
+
+        if offers_mercy {
+            if Self::player_resigns(input, output)? {
+                return Ok(TurnEnd::Resigned);
+            }
+
+            self.mercy_declined = true;
+        }
+
+        let time_control = self.time_control(self.engine.current_turn);
+
+        if let Some(time_control) = time_control {
+            writeln!(
+                output,
+                "{} has {:.1}s to move.",
+                self.current_token(),
+                time_control.as_secs_f64()
+            )?;
+        }
+
+        let valid_move = match self.engine.current_turn {
+            Turn::Bot if !self.config.two_player => Some(self.get_bot_move(output)?),
+            turn => {
+                let started = Instant::now();
+                let player_move = self.get_player_move(input, output)?;
+
+                if time_control.is_some_and(|time_control| started.elapsed() > time_control) {
+                    return Ok(TurnEnd::TimedOut);
+                }
+
+                if self.config.speed_leaderboard && player_move.is_some() {
+                    self.move_times.push((turn, started.elapsed()));
+                }
+
+                player_move
+            }
+        };
+
+        match valid_move {
+            Some(valid_move) => {
+                self.place_move(valid_move);
+
+                Ok(TurnEnd::Moved)
+            }
+            None => Ok(TurnEnd::DrawAgreed),
+        }
+    }
+
+    /// Assigns the tokens to the sides by the token rule, before the first move of a game.
+    fn assign_tokens(&mut self) {
+        if self.remaining() != self.engine.cell_count() as usize {
+            return;
+        }
+
+        self.engine.x_side = match self.config.token_rule {
+            TokenRule::FixedSide => Turn::Player,
+            TokenRule::FirstMoverIsX => self.engine.current_turn,
+        };
+    }
+
+    /// Determines if the side to move is proven to lose, i.e. every move loses against best play.
+    ///
+    /// Positions with too many open cells to search are never considered lost.
+    fn position_is_lost(&self) -> bool {
+        match self.ranked_moves_with_plies().first() {
+            Some(&(_, outcome, _)) => outcome == Outcome::Loss,
+            None => false,
+        }
+    }
+
+    /// Asks the player whether they resign their lost position or play on.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to read the answer from.
+    /// * `output` - The output to write the question to.
+    fn player_resigns<I: Input, W: Write>(input: &mut I, output: &mut W) -> io::Result<bool> {
+        Self::prompt(
+            input,
+            output,
+            "This position is lost \u{2014} resign or play on?",
+            Self::parse_resign_answer,
+        )
+    }
+
+    /// Parses the answer to the mercy rule's offer, whether the player resigns.
+    ///
+    /// # Arguments
+    ///
+    /// * `answer` - The answer to parse.
+    fn parse_resign_answer(answer: &str) -> Result<bool, String> {
+        match answer.trim().to_lowercase().as_str() {
+            "resign" | "r" => Ok(true),
+            "play on" | "play" | "p" => Ok(false),
+            _ => Err(String::from("Please input resign or play on!")),
+        }
+    }
+
+    /// Places an obstacle on a cell of the board. The lines are left to the caller to update.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - A cell that has already been validated.
+    fn place_obstacle(&mut self, cell: u32) {
+        let (row, col) = self.engine.move_to_board_location(cell);
+
+        self.engine.board[row][col] = String::from(OBSTACLE);
+    }
+
+    /// Places the token of the current turn on the board and records the move.
+    ///
+    /// # Arguments
+    ///
+    /// * `valid_move` - A move that has already been validated.
+    fn place_move(&mut self, valid_move: u32) {
+        let (row, col) = self.engine.move_to_board_location(valid_move);
+        let overwritten_token = match self.engine.cell_state(row, col) {
+            CellState::X | CellState::O => Some(self.engine.board[row][col].clone()),
+            _ => None,
+        };
+
+        self.engine.board[row][col] = self
+            .engine
+            .turn_token(&self.engine.current_turn)
+            .to_string();
+        self.history.push(valid_move);
+        self.overwritten_tokens.push(overwritten_token);
+        self.last_bot_move = match self.engine.current_turn {
+            Turn::Player => None,
+            Turn::Bot => Some(valid_move),
+        };
+
+        self.emit(GameEvent::MovePlayed(self.engine.current_turn, valid_move));
+
+        if let Some(Commentator(ref commentator)) = self.commentator {
+            // A commentator that panicked before still narrates the later moves.
+            let mut comment = commentator
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+            comment(self, self.engine.current_turn, valid_move);
+        }
+    }
+
+    /// Prints the game board
+    ///
+    /// Prints as:
+    /// +---+---+---+
+    /// | 1 | 2 | 3 |
+    /// +---+---+---+
+    /// | 4 | 5 | 6 |
+    /// +---+---+---+
+    /// | 7 | 8 | 9 |
+    /// +---+---+---+
+    fn print_board(&self) -> io::Result<()> {
+        self.render_to(&mut io::stdout())
+    }
+
+    /// Writes the game board as it is printed, e.g. to a file or a buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Where to write the board.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::game::Game;
+    ///
+    /// let game = Game::new();
+    /// let mut rendered = Vec::new();
+    ///
+    /// game.render_to(&mut rendered).unwrap();
+    ///
+    /// assert!(String::from_utf8(rendered).unwrap().contains("| 1 | 2 | 3 |"));
+    /// ```
+    pub fn render_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "{}", self.render_board())?;
+        writer.flush()
+    }
+
+    /// Renders the game board as it is printed, marking the last move and the open cells if
+    /// enabled.
+    ///
+    /// With the fog of war, the bot's last move is masked as "?" until the player moves. The
+    /// cells are spaced by the render options and colored by the theme.
+    fn render_board(&self) -> String {
+        let cell_width = self.engine.cell_count().to_string().len();
+        let RenderOptions {
+            padding,
+            row_spacing,
+        } = self.config.render_options;
+        let theme = self.config.theme;
+        let seperator = theme.paint(
+            &format!(
+                "+{}",
+                format!("{}+", "-".repeat(cell_width + 2 * padding)).repeat(self.engine.cols())
+            ),
+            ThemePart::Border,
+        );
+        let bar = theme.paint("|", ThemePart::Border);
+        let spacing = format!(
+            "{}{}\n",
+            bar,
+            format!("{}{}", " ".repeat(cell_width + 2 * padding), bar).repeat(self.engine.cols())
+        )
+        .repeat(row_spacing);
+        let pad_cell = |cell: &str, open: &str, close: &str| {
+            let centered = format!("{:^width$}", cell, width = cell_width);
+            let content = match cell {
+                "X" => theme.paint(&centered, ThemePart::X),
+                "O" => theme.paint(&centered, ThemePart::O),
+                _ => centered,
+            };
+
+            if padding == 0 {
+                return content;
+            }
+
+            let inner = " ".repeat(padding - 1);
+            let mark = |marker: &str| match marker {
+                " " => marker.to_string(),
+                _ => theme.paint(marker, ThemePart::Marker),
+            };
+
+            format!("{}{}{}{}{}", mark(open), inner, content, inner, mark(close))
+        };
+        let last_location = match self.history.last() {
+            Some(last_move) if self.config.mark_last_move => {
+                Some(self.engine.move_to_board_location(*last_move))
+            }
+            _ => None,
+        };
+        let fog_location = match self.last_bot_move {
+            Some(bot_move) if self.config.fog_of_war => {
+                Some(self.engine.move_to_board_location(bot_move))
+            }
+            _ => None,
+        };
+        let legal_locations: Vec<(usize, usize)> = if self.config.highlight_legal {
+            self.available_moves()
+                .into_iter()
+                .map(|game_move| self.engine.move_to_board_location(game_move))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut rendered = format!("\n{}\n", seperator);
+
+        for (row_index, row) in self.engine.board.iter().enumerate() {
+            let cells: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(col_index, cell)| {
+                    let is_open = self
+                        .engine
+                        .is_open_move((row_index * row.len() + col_index + 1) as u32);
+                    let cell = if fog_location == Some((row_index, col_index)) {
+                        "?"
+                    } else if is_open && self.config.show_empty_as == EmptyStyle::Blank {
+                        " "
+                    } else if is_open && self.config.show_empty_as == EmptyStyle::Dot {
+                        "."
+                    } else {
+                        cell.as_str()
+                    };
+
+                    if last_location == Some((row_index, col_index)) {
+                        pad_cell(cell, "[", "]")
+                    } else if legal_locations.contains(&(row_index, col_index)) {
+                        pad_cell(cell, "(", ")")
+                    } else {
+                        pad_cell(cell, " ", " ")
+                    }
+                })
+                .collect();
+
+            rendered.push_str(&format!(
+                "{}{}{}{}\n{}{}\n",
+                spacing,
+                bar,
+                cells.join(&bar),
+                bar,
+                spacing,
+                seperator
+            ));
+        }
+
+        if self.config.show_move_numbers_legend {
+            rendered.push_str(&format!("\nMove numbers:\n{}\n", seperator));
+
+            for row in Engine::empty_board(self.engine.rows(), self.engine.cols()) {
+                let cells: Vec<String> = row.iter().map(|cell| pad_cell(cell, " ", " ")).collect();
+
+                rendered.push_str(&format!(
+                    "{}{}{}{}\n{}{}\n",
+                    spacing,
+                    bar,
+                    cells.join(&bar),
+                    bar,
+                    spacing,
+                    seperator
+                ));
+            }
+        }
+
+        rendered.push('\n');
+
+        rendered
+    }
+
+    /// Gets move from player.
+    ///
+    /// The "help" command prints the commands and rules, then asks for the move again. In
+    /// two-player mode, the "draw" command offers a draw to the other player, and `None` is
+    /// returned if they accept it. In debug builds, the "setup" command places several tokens at
+    /// once with `apply_setup`. Fails if the input is interrupted or closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to read the move from.
+    /// * `output` - The output to write the prompts to.
+    fn get_player_move<I: Input, W: Write>(
+        &mut self,
+        input: &mut I,
+        output: &mut W,
+    ) -> io::Result<Option<u32>> {
+        loop {
+            if self.config.two_player {
+                writeln!(output, "\n{} to move.", self.current_token())?;
+            }
+
+            writeln!(
+                output,
+                "\nPlease enter your move (an integer between 1 and {}), or \"help\": ",
+                self.engine.cell_count()
+            )?;
+
+            if self.config.show_commands_hint {
+                writeln!(output, "{}", self.commands_hint())?;
+            }
+
+            match input.read_line() {
+                Err(ref err) if err.kind() == io::ErrorKind::InvalidData => {
+                    writeln!(output, "Error reading input, try again!")?
+                }
+                Err(ref err) if err.kind() == io::ErrorKind::InvalidInput => {
+                    writeln!(output, "Input is too long, try again!")?
+                }
+                Err(err) => return Err(err),
+                Ok(ref player_input) if player_input.trim().eq_ignore_ascii_case("help") => {
+                    writeln!(output, "{}", self.help_text())?
+                }
+                Ok(ref player_input)
+                    if self.config.two_player
+                        && player_input.trim().eq_ignore_ascii_case("draw") =>
+                {
+                    if self.draw_is_accepted(input, output)? {
+                        return Ok(None);
+                    }
+
+                    writeln!(output, "The draw was declined.")?
+                }
+                Ok(ref player_input) if player_input.trim().starts_with("try ") => {
+                    self.explore_branch(player_input.trim(), input, output)?
+                }
+                Ok(ref player_input)
+                    if self.config.two_player
+                        && player_input.trim().eq_ignore_ascii_case("undo") =>
+                {
+                    if self.history.len() < 2 {
+                        writeln!(output, "There is no move to undo.")?
+                    } else if self.undo_is_allowed(input, output)? {
+                        // The reply of the other player is taken back along with the move.
+                        self.take_back_move();
+                        self.take_back_move();
+                        self.render_to(output)?
+                    } else {
+                        writeln!(output, "The undo was denied.")?
+                    }
+                }
+                Ok(ref player_input)
+                    if cfg!(debug_assertions) && player_input.trim().starts_with("setup ") =>
+                {
+                    match self.apply_setup(&player_input.trim()["setup ".len()..]) {
+                        Ok(()) => self.render_to(output)?,
+                        Err(err) => writeln!(output, "{}", err)?,
+                    }
+                }
+                Ok(player_input) => match self.validate_player_input(&player_input) {
+                    Err(err) => writeln!(output, "{}", err)?,
+                    Ok(num)
+                        if !self.config.confirm_moves
+                            || self.move_is_confirmed(num, input, output)? =>
+                    {
+                        return Ok(Some(num))
+                    }
+                    Ok(_) => writeln!(output, "The move was cancelled.")?,
+                },
+            }
+        }
+    }
+
+    /// Asks the other player whether they accept the draw offered by the side to move.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to read the answer from.
+    /// * `output` - The output to write the question to.
+    fn draw_is_accepted<I: Input, W: Write>(
+        &self,
+        input: &mut I,
+        output: &mut W,
+    ) -> io::Result<bool> {
+        let question = format!(
+            "{} offers a draw. {}, do you accept (y/n)?:",
+            self.current_token(),
+            self.engine.turn_token(&self.engine.get_next_turn())
+        );
+
+        Self::prompt(input, output, &question, Self::parse_yes_no)
+    }
+
+    /// Explores a what-if branch of the game, starting with a "try" command, on a snapshot of
+    /// the game. Each "try" plays a move on the branch and writes the board and its evaluation,
+    /// until "back" returns to the real position, which is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The "try" command that started the exploration.
+    /// * `input` - The input to read the further commands from.
+    /// * `output` - The output to write the branch to.
+    fn explore_branch<I: Input, W: Write>(
+        &self,
+        command: &str,
+        input: &mut I,
+        output: &mut W,
+    ) -> io::Result<()> {
+        let mut branch = self.snapshot();
+        let mut next_move = Self::parse_branch_command(command);
+
+        loop {
+            match next_move {
+                Ok(None) => break,
+                Ok(Some(game_move)) => match branch.apply_move(game_move) {
+                    Ok(()) => {
+                        branch.render_to(output)?;
+                        writeln!(output, "{}", branch.evaluation_summary())?;
+                    }
+                    Err(err) => writeln!(output, "{}", err)?,
+                },
+                Err(err) => writeln!(output, "{}", err)?,
+            }
+
+            next_move = Ok(Self::prompt(
+                input,
+                output,
+                "Type \"try <move>\" to explore further, or \"back\" to return:",
+                Self::parse_branch_command,
+            )?);
+        }
+
+        writeln!(output, "Back to the real position.")?;
+        self.render_to(output)
+    }
+
+    /// Parses a command of a what-if branch, "try <move>" to play a move or "back" to return.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command to parse.
+    fn parse_branch_command(command: &str) -> Result<Option<u32>, String> {
+        let command = command.trim().to_lowercase();
+
+        if command == "back" {
+            return Ok(None);
+        }
+
+        match command
+            .strip_prefix("try ")
+            .map(|game_move| game_move.trim().parse::<u32>())
+        {
+            Some(Ok(game_move)) => Ok(Some(game_move)),
+            _ => Err(String::from("Please input try <move> or back!")),
+        }
+    }
+
+    /// Gets the evaluation of the position for the side to move, with the `advantage`, e.g.
+    /// "Evaluation: a draw for O in 8 plies, advantage +0.00."
+    fn evaluation_summary(&self) -> String {
+        if self.remaining() > MAX_SEARCH_CELLS && !self.is_over() {
+            return format!("Evaluation: advantage {:+.2}.", self.advantage());
+        }
+
+        match self.outcome_with_distance() {
+            Some((outcome, plies)) => format!(
+                "Evaluation: a {} for {} in {} plies, advantage {:+.2}.",
+                outcome,
+                self.current_token(),
+                plies,
+                self.advantage()
+            ),
+            None => match self.engine.winner() {
+                Some(winner) => format!("Evaluation: {} has won.", self.engine.turn_token(&winner)),
+                None => String::from("Evaluation: the game is drawn."),
+            },
+        }
+    }
+
+    /// Asks the other player whether they allow the side to move to take back its last move,
+    /// along with their reply to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to read the answer from.
+    /// * `output` - The output to write the question to.
+    fn undo_is_allowed<I: Input, W: Write>(
+        &self,
+        input: &mut I,
+        output: &mut W,
+    ) -> io::Result<bool> {
+        let question = format!(
+            "{} wants to undo their last move. {}, allow undo? (y/n)",
+            self.current_token(),
+            self.engine.turn_token(&self.engine.get_next_turn())
+        );
+
+        Self::prompt(input, output, &question, Self::parse_yes_no)
+    }
+
+    /// Takes back the last move of the history, giving the turn back to the side that played it.
+    fn take_back_move(&mut self) {
+        if let Some(last_move) = self.history.pop() {
+            self.engine.unmake_move(last_move);
+            self.last_bot_move = None;
+
+            if let Some(Some(token)) = self.overwritten_tokens.pop() {
+                let (row, col) = self.engine.move_to_board_location(last_move);
+
+                self.engine.board[row][col] = token;
+            }
+        }
+    }
+
+    /// Asks the player to confirm the placement of a move.
+    ///
+    /// # Arguments
+    ///
+    /// * `valid_move` - A move that has already been validated.
+    /// * `input` - The input to read the answer from.
+    /// * `output` - The output to write the question to.
+    fn move_is_confirmed<I: Input, W: Write>(
+        &self,
+        valid_move: u32,
+        input: &mut I,
+        output: &mut W,
+    ) -> io::Result<bool> {
+        let question = format!(
+            "Place {} on {}. Confirm? (y/n)",
+            self.current_token(),
+            valid_move
+        );
+
+        Self::prompt(input, output, &question, Self::parse_yes_no)
+    }
+
+    /// Parses a yes or no answer, e.g. to a draw offer, an undo request, or a move confirmation.
+    ///
+    /// # Arguments
+    ///
+    /// * `answer` - The answer to parse.
+    fn parse_yes_no(answer: &str) -> Result<bool, String> {
+        match answer.trim().to_lowercase().as_str() {
+            "y" | "yes" => Ok(true),
+            "n" | "no" => Ok(false),
+            _ => Err(String::from("Please input y or n!")),
+        }
+    }
+
+    /// Gets the reminder of the commands the player can type, listing only the commands of the
+    /// enabled features. The debug-only commands are left to the help.
+    fn commands_hint(&self) -> String {
+        let mut commands = vec!["help", "try"];
+
+        if self.config.two_player {
+            commands.push("draw");
+            commands.push("undo");
+        }
+
+        format!("Commands: {}", commands.join(", "))
+    }
+
+    /// Gets the help printed by the "help" command, listing the commands and the rules of the
+    /// enabled options.
+    fn help_text(&self) -> String {
+        let mut help = String::from("Commands:\n  help - Show this help.");
+
+        help.push_str("\n  try 5 - Explore playing 5 without committing it, until \"back\".");
+
+        if self.config.two_player {
+            help.push_str("\n  draw - Offer a draw to the other player.");
+            help.push_str(
+                "\n  undo - Ask the other player to take back your last move and their reply.",
+            );
+        }
+
+        if cfg!(debug_assertions) {
+            help.push_str("\n  setup X:5 O:1 ... - Place several tokens at once.");
+        }
+
+        help.push_str("\n  Ctrl-C - Quit the session");
+
+        if let Some(ref path) = self.config.interrupt_save_path {
+            help.push_str(&format!(", saving the game to {}", path.display()));
+        }
+
+        help.push_str(&format!(
+            ".\nRules:\n  Take turns placing tokens, {}. The first to get {} in a row, across, \
+             down, or diagonally, wins.",
+            if self.config.two_player {
+                "X and then O"
+            } else {
+                "you as X and the bot as O"
+            },
+            self.engine.win_length
+        ));
+
+        if self.engine.toroidal {
+            help.push_str("\n  The lines wrap around the edges of the board.");
+        }
+
+        if self.config.fog_of_war {
+            help.push_str("\n  The bot's last move is hidden as \"?\" until you move.");
+        }
+
+        help
+    }
+
+    /// Validates player input, with the built-in checks and then the custom check if set.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_input` - The input take from the player.
+    fn validate_player_input(&self, player_input: &str) -> Result<u32, String> {
+        match player_input.trim().parse::<u32>() {
+            Err(_) => Err(String::from("Please input a valid unsigned integer!")),
+            Ok(number) => {
+                if !self.engine.is_valid_move(number) {
+                    return Err(self.engine.invalid_move_message());
+                }
+
+                if let Some(MoveValidator(ref validator)) = self.move_validator {
+                    validator(number, self)?;
+                }
+
+                Ok(number)
+            }
+        }
+    }
+
+    /// Gets move from bot, based on its difficulty.
+    ///
+    /// The move is chosen with `choose_bot_move`, and explained if the bot explains its moves.
+    ///
+    /// # Arguments
+    ///
+    /// * `output` - The output to write the bot's messages to.
+    fn get_bot_move<W: Write>(&mut self, output: &mut W) -> io::Result<u32> {
+        if self.config.think_out_loud {
+            writeln!(output, "Bot is thinking...")?;
+        }
+
+        let started = Instant::now();
+        let (bot_move, reason) = self.choose_bot_move();
+
+        self.last_decision = Some(self.decision(bot_move, reason));
+        self.bot_moves.push(bot_move);
+
+        if self.config.think_out_loud {
+            writeln!(
+                output,
+                "{}",
+                Self::bot_moved_message(bot_move, started.elapsed())
+            )?;
+        } else {
+            writeln!(output, "Bot played moved at: {}", bot_move)?;
+        }
+
+        if self.config.explain_moves {
+            writeln!(output, "{}", Self::move_reason_message(bot_move, reason))?;
+        }
+
+        if self.config.challenge {
+            if let Some(taunt) = self.challenge_taunt(bot_move) {
+                writeln!(output, "{}", taunt)?;
+            }
+        }
+
+        Ok(bot_move)
+    }
+
+    /// Chooses the bot's move along with the reason for it, by the script, the copycat, the
+    /// teaching bot, or the difficulty, in that order, among the bot's candidate moves.
+    fn choose_bot_move(&mut self) -> (u32, MoveReason) {
+        let candidates = self.bot_candidates();
+
+        if let Some(scripted_move) = self.scripted_move(&candidates) {
+            return (scripted_move, MoveReason::Scripted);
+        }
+
+        if let Some(mirrored_move) = self.mirrored_move() {
+            return (mirrored_move, MoveReason::Mirrored);
+        }
+
+        if let Some(teaching_move) = self.teaching_move(&candidates) {
+            return (teaching_move, MoveReason::Teaching);
+        }
+
+        match self.bot_difficulty() {
+            Difficulty::Easy => (self.random_move(&candidates), MoveReason::Random),
+            Difficulty::Medium => self.heuristic_move(&candidates),
+            Difficulty::Hard => self.search_move(&candidates),
+        }
+    }
+
+    /// Gets the moves the bot may choose from, the open moves, except for the center when the
+    /// bot avoids the center opening, no token is on the board yet, and another move is open.
+    fn bot_candidates(&self) -> Vec<u32> {
+        let moves = self.available_moves();
+        let opening =
+            moves.len() + self.config.obstacles.len() == self.engine.cell_count() as usize;
+
+        if !self.config.bot_avoid_center_opening || !opening || moves.len() < 2 {
+            return moves;
+        }
+
+        moves
+            .into_iter()
+            .filter(|&game_move| Some(game_move) != self.center_move())
+            .collect()
+    }
+
+    /// Gets the decision behind a move the bot chose, before it is played.
+    ///
+    /// # Arguments
+    ///
+    /// * `bot_move` - The move the bot chose.
+    /// * `reason` - The reason the bot chose the move.
+    fn decision(&self, bot_move: u32, reason: MoveReason) -> Decision {
+        let outcomes: Vec<(u32, Outcome)> = self
+            .ranked_moves_with_plies()
+            .into_iter()
+            .map(|(game_move, outcome, _)| (game_move, outcome))
+            .collect();
+        let candidates = self
+            .available_moves()
+            .into_iter()
+            .map(|game_move| {
+                let outcome = outcomes
+                    .iter()
+                    .find(|&&(ranked_move, _)| ranked_move == game_move)
+                    .map(|&(_, outcome)| outcome);
+
+                (game_move, outcome)
+            })
+            .collect();
+
+        Decision {
+            candidates,
+            chosen: bot_move,
+            reason: Self::move_reason(bot_move, reason),
+        }
+    }
+
+    /// Gets the bot's explanation of a move.
+    ///
+    /// # Arguments
+    ///
+    /// * `bot_move` - The move the bot played.
+    /// * `reason` - The reason the bot chose the move.
+    fn move_reason_message(bot_move: u32, reason: MoveReason) -> String {
+        format!("Bot's reason: {}", Self::move_reason(bot_move, reason))
+    }
+
+    /// Gets the reason for a move of the bot, e.g. "taking the win at 3".
+    ///
+    /// # Arguments
+    ///
+    /// * `bot_move` - The move the bot played.
+    /// * `reason` - The reason the bot chose the move.
+    fn move_reason(bot_move: u32, reason: MoveReason) -> String {
+        match reason {
+            MoveReason::Scripted => format!("following the script at {}", bot_move),
+            MoveReason::Mirrored => format!("mirroring your move at {}", bot_move),
+            MoveReason::Win => format!("taking the win at {}", bot_move),
+            MoveReason::Block => format!("blocking your threat at {}", bot_move),
+            MoveReason::Random => format!("playing at random at {}", bot_move),
+            MoveReason::Search => format!("playing {}, the best by search", bot_move),
+            MoveReason::Center => format!("playing center at {}", bot_move),
+            MoveReason::Teaching => format!("playing {}, keeping the game drawn", bot_move),
+        }
+    }
+
+    /// Gets the message of the bot thinking out loud once it moved, with the time the move took.
+    ///
+    /// # Arguments
+    ///
+    /// * `bot_move` - The move the bot played.
+    /// * `elapsed` - The time the bot took to choose the move.
+    fn bot_moved_message(bot_move: u32, elapsed: Duration) -> String {
+        format!("Bot moved at {} ({}ms)", bot_move, elapsed.as_millis())
+    }
+
+    /// Gets the difficulty the bot plays its next move at.
+    ///
+    /// With the adaptive difficulty, the bot plays the opening (its first move on a board with at
+    /// most one token) easy, the endgame (at most `ENDGAME_CELLS` open cells) hard, and the rest
+    /// medium.
+    fn bot_difficulty(&self) -> Difficulty {
+        if !self.config.adaptive_difficulty {
+            return self.config.difficulty;
+        }
+
+        let remaining = self.remaining();
+
+        if remaining + 1 >= self.engine.cell_count() as usize {
+            Difficulty::Easy
+        } else if remaining <= ENDGAME_CELLS {
+            Difficulty::Hard
+        } else {
+            Difficulty::Medium
+        }
+    }
+
+    /// Takes the next move of the bot's script, if there is one and it is one of the candidates.
+    ///
+    /// # Arguments
+    ///
+    /// * `candidates` - The moves the bot may choose from.
+    fn scripted_move(&mut self, candidates: &[u32]) -> Option<u32> {
+        self.bot_script
+            .pop_front()
+            .filter(|scripted_move| candidates.contains(scripted_move))
+    }
+
+    /// Gets the player's last move mirrored across the center of the board, if the bot is a
+    /// copycat and the mirrored cell is open.
+    fn mirrored_move(&self) -> Option<u32> {
+        if !self.config.copycat {
+            return None;
+        }
+
+        let (row, col) = self.engine.move_to_board_location(*self.history.last()?);
+        let mirrored_move = ((self.engine.rows() - 1 - row) * self.engine.cols()
+            + (self.engine.cols() - 1 - col)
+            + 1) as u32;
+
+        Some(mirrored_move).filter(|&mirrored_move| self.engine.is_open_move(mirrored_move))
+    }
+
+    /// Gets a random move of the teaching bot that keeps the game drawn. In a drawn position,
+    /// these are the safe moves, and in a won position, the moves that give up the win for a
+    /// draw.
+    ///
+    /// Returns `None` if the bot isn't a teaching bot, no candidate draws, or too many cells are
+    /// open to search.
+    ///
+    /// # Arguments
+    ///
+    /// * `candidates` - The moves the bot may choose from.
+    fn teaching_move(&mut self, candidates: &[u32]) -> Option<u32> {
+        if !self.config.teaching {
+            return None;
+        }
+
+        let ranked = self.ranked_moves_with_plies();
+        let mut drawing: Vec<u32> = match ranked.first() {
+            Some(&(_, Outcome::Draw, _)) => self.safe_moves(),
+            _ => ranked
+                .into_iter()
+                .filter(|&(_, outcome, _)| outcome == Outcome::Draw)
+                .map(|(game_move, _, _)| game_move)
+                .collect(),
+        };
+
+        drawing.retain(|game_move| candidates.contains(game_move));
+
+        self.rng.choose(&drawing).cloned()
+    }
+
+    /// Gets the bot's taunt for a move, from its minimax evaluation of the move.
+    ///
+    /// Returns `None` when too many cells are open to search.
+    ///
+    /// # Arguments
+    ///
+    /// * `bot_move` - A move of the bot that has already been validated.
+    fn challenge_taunt(&self, bot_move: u32) -> Option<&'static str> {
+        let (_, outcome, _) = self
+            .ranked_moves_with_plies()
+            .into_iter()
+            .find(|&(game_move, _, _)| game_move == bot_move)?;
+
+        Some(match outcome {
+            Outcome::Win => "I'm winning!",
+            Outcome::Draw => "Let's call it a draw",
+            Outcome::Loss => "You've got me!",
+        })
+    }
+
+    /// Gets a random move for the bot.
+    ///
+    /// # Arguments
+    ///
+    /// * `candidates` - The moves the bot may choose from, at least one.
+    fn random_move(&mut self, candidates: &[u32]) -> u32 {
+        let cell_count = self.engine.cell_count();
+        let mut bot_move: u32 = self.rng.gen_range(1, cell_count + 1);
+
+        while !candidates.contains(&bot_move) {
+            bot_move = self.rng.gen_range(1, cell_count + 1);
+        }
+
+        bot_move
+    }
+
+    /// Gets a move for the bot that takes a win, or else blocks the player's win, or else is
+    /// random, along with the reason for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `candidates` - The moves the bot may choose from at random.
+    fn heuristic_move(&mut self, candidates: &[u32]) -> (u32, MoveReason) {
+        if let Some(winning_move) = self.immediate_win() {
+            (winning_move, MoveReason::Win)
+        } else if let Some(blocking_move) = self.engine.winning_move(Turn::Player) {
+            (blocking_move, MoveReason::Block)
+        } else {
+            (self.random_move(candidates), MoveReason::Random)
+        }
+    }
+
+    /// Gets the best move for the bot with minimax, once few enough cells are open to search,
+    /// along with the reason for it, which is the center when the tie-break chose the center.
+    ///
+    /// # Arguments
+    ///
+    /// * `candidates` - The moves the bot may choose from.
+    fn search_move(&mut self, candidates: &[u32]) -> (u32, MoveReason) {
+        let budget = match (self.config.search_budget, self.time_control(Turn::Bot)) {
+            (Some(budget), Some(time_control)) => Some(budget.min(time_control)),
+            (budget, time_control) => budget.or(time_control),
+        };
+
+        if let Some(budget) = budget {
+            let budgeted_move = self.engine.clone().best_move_within(budget, candidates);
+
+            return (budgeted_move, MoveReason::Search);
+        }
+
+        if self.available_moves().len() > MAX_SEARCH_CELLS {
+            self.heuristic_move(candidates)
+        } else {
+            let best_move = self.best_move(candidates);
+            let prefers_center = self.config.tie_break == TieBreak::Center;
+
+            if prefers_center && Some(best_move) == self.center_move() {
+                (best_move, MoveReason::Center)
+            } else {
+                (best_move, MoveReason::Search)
+            }
+        }
+    }
+
+    /// Gets the best of the candidate moves for the side to move with minimax, choosing among
+    /// equally good moves by the tie-break preference.
+    ///
+    /// # Arguments
+    ///
+    /// * `candidates` - The moves to choose from, at least one.
+    fn best_move(&mut self, candidates: &[u32]) -> u32 {
+        let mut ranked = self.ranked_moves_with_plies();
+
+        ranked.retain(|&(game_move, _, _)| candidates.contains(&game_move));
+
+        let best_score = Self::outcome_score(ranked[0].1, ranked[0].2);
+        let tied: Vec<u32> = ranked
+            .into_iter()
+            .filter(|&(_, outcome, plies)| Self::outcome_score(outcome, plies) == best_score)
+            .map(|(game_move, _, _)| game_move)
+            .collect();
+
+        match self.config.tie_break {
+            TieBreak::First => tied[0],
+            TieBreak::Random => *self.rng.choose(&tied).unwrap(),
+            TieBreak::Center => *tied
+                .iter()
+                .min_by_key(|&&game_move| self.distance_to_center(game_move))
+                .unwrap(),
+            TieBreak::Corner => tied
+                .iter()
+                .cloned()
+                .find(|&game_move| self.is_corner(game_move))
+                .unwrap_or(tied[0]),
+            TieBreak::Traps => *tied
+                .iter()
+                .rev()
+                .max_by_key(|&&game_move| self.losing_replies(game_move))
+                .unwrap(),
+        }
+    }
+
+    /// Counts the replies to a move that lose for the opponent against best play, with minimax.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_move` - A move that has already been validated.
+    fn losing_replies(&self, game_move: u32) -> usize {
+        let mut search = self.engine.clone();
+
+        search.make_move(game_move);
+
+        if search.game_is_won() {
+            return 0;
+        }
+
+        search
+            .available_moves()
+            .into_iter()
+            .filter(|&reply| search.evaluate_move(reply).0 == Outcome::Loss)
+            .count()
+    }
+
+    /// Gets the squared distance of a move from the center of the board, in half cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_move` - The move to measure.
+    fn distance_to_center(&self, game_move: u32) -> isize {
+        let (row, col) = self.engine.move_to_board_location(game_move);
+        let row_distance = 2 * row as isize - (self.engine.rows() as isize - 1);
+        let col_distance = 2 * col as isize - (self.engine.cols() as isize - 1);
+
+        row_distance * row_distance + col_distance * col_distance
+    }
+
+    /// Checks whether a move is in a corner of the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_move` - The move to check.
+    fn is_corner(&self, game_move: u32) -> bool {
+        let (row, col) = self.engine.move_to_board_location(game_move);
+        let last_row = self.engine.rows() - 1;
+        let last_col = self.engine.cols() - 1;
+
+        (row == 0 || row == last_row) && (col == 0 || col == last_col)
+    }
+
+    /// Gets the move of the center cell, if the board has one.
+    fn center_move(&self) -> Option<u32> {
+        let cell_count = self.engine.cell_count();
+
+        if cell_count % 2 == 1 {
+            Some(cell_count / 2 + 1)
+        } else {
+            None
+        }
+    }
+
+    /// Checks that a board size is supported, and that its number of cells fits in a move.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The number of rows and columns.
+    fn check_size(size: usize) -> Result<(), String> {
+        let cells_fit = size
+            .checked_mul(size)
+            .is_some_and(|cells| cells <= u32::MAX as usize);
+
+        if !(MIN_SIZE..=MAX_SIZE).contains(&size) || !cells_fit {
+            return Err(format!(
+                "The board size must be between {} and {}!",
+                MIN_SIZE, MAX_SIZE
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Copies the board options of the engine into the configuration, after a builder method
+    /// changed them.
+    fn sync_board_config(&mut self) {
+        self.config.rows = self.engine.rows();
+        self.config.cols = self.engine.cols();
+        self.config.win_length = self.engine.win_length;
+        self.config.toroidal = self.engine.toroidal;
+        self.config.win_directions = self.engine.win_directions;
+        self.config.allow_overwrite = self.engine.allow_overwrite;
+    }
+
+    /// Prompts until an answer is parsed successfully, writing the error of each invalid answer.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to read the answers from.
+    /// * `output` - The output to write the prompts to.
+    /// * `question` - The prompt for the answer.
+    /// * `parse` - Parses an answer, or gets the error of an invalid answer.
+    fn prompt<I, W, T, F>(
+        input: &mut I,
+        output: &mut W,
+        question: &str,
+        mut parse: F,
+    ) -> io::Result<T>
+    where
+        I: Input,
+        W: Write,
+        F: FnMut(&str) -> Result<T, String>,
+    {
+        loop {
+            writeln!(output, "{}", question)?;
+
+            match input.read_line() {
+                Err(ref err) if err.kind() == io::ErrorKind::InvalidData => {
+                    writeln!(output, "Error reading input, try again!")?
+                }
+                Err(ref err) if err.kind() == io::ErrorKind::InvalidInput => {
+                    writeln!(output, "Input is too long, try again!")?
+                }
+                Err(err) => return Err(err),
+                Ok(answer) => match parse(answer.trim()) {
+                    Err(err) => writeln!(output, "{}", err)?,
+                    Ok(value) => return Ok(value),
+                },
+            }
+        }
+    }
+
+    /// Parses an answer as an unsigned integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `answer` - The answer to parse.
+    fn parse_number(answer: &str) -> Result<usize, String> {
+        answer
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| String::from("Please input a valid unsigned integer!"))
+    }
+
+    /// Ranks the legal moves like `ranked_moves`, keeping the number of plies each move takes to
+    /// reach its outcome. The ranking is empty once the game is over, or while more than
+    /// `MAX_SEARCH_CELLS` cells are open, too many to search.
+    ///
+    /// The ranking is cached by `board_hash`, so it is only searched once per position.
+    fn ranked_moves_with_plies(&self) -> RankedMoves {
+        if self.is_over() || self.remaining() > MAX_SEARCH_CELLS {
+            return Vec::new();
+        }
+
+        let hash = self.board_hash();
+
+        if let Some((cached_hash, ref ranked)) = *self.evaluation_cache.borrow() {
+            if cached_hash == hash {
+                return ranked.clone();
+            }
+        }
+
+        let mut search = self.engine.clone();
+        let mut evaluated: RankedMoves = self
+            .available_moves()
+            .into_iter()
+            .map(|game_move| {
+                let (outcome, plies) = search.evaluate_move(game_move);
+
+                (game_move, outcome, plies)
+            })
+            .collect();
+
+        evaluated.sort_by_key(|&(_, outcome, plies)| -Self::outcome_score(outcome, plies));
+
+        self.searches.set(self.searches.get() + 1);
+        *self.evaluation_cache.borrow_mut() = Some((hash, evaluated.clone()));
+
+        evaluated
+    }
+
+    /// Hashes the position and the rules that decide its outcome, so a changed board gets a new
+    /// hash. The winning lines stand in for every rule that shapes them, e.g. the win length, a
+    /// toroidal board, or the win directions.
+    fn board_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        self.engine.board.hash(&mut hasher);
+        self.engine.current_turn.hash(&mut hasher);
+        self.engine.x_side.hash(&mut hasher);
+        self.engine.win_lines.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Scores an outcome so that quicker wins and slower losses score higher.
+    ///
+    /// # Arguments
+    ///
+    /// * `outcome` - The outcome to score.
+    /// * `plies` - The number of plies until the outcome is reached.
+    fn outcome_score(outcome: Outcome, plies: u32) -> i32 {
+        match outcome {
+            Outcome::Win => 100 - plies as i32,
+            Outcome::Draw => 0,
+            Outcome::Loss => plies as i32 - 100,
+        }
+    }
+
+    /// Waits for the player to press enter, e.g. to look at the final board.
+    ///
+    /// Fails if the input is interrupted or closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to wait for enter on.
+    /// * `output` - The output to write the request to.
+    fn wait_for_acknowledgement<I: Input, W: Write>(
+        input: &mut I,
+        output: &mut W,
+    ) -> io::Result<()> {
+        writeln!(output, "Press enter to continue...")?;
+
+        match input.read_line() {
+            Ok(_) => Ok(()),
+            Err(ref err)
+                if err.kind() == io::ErrorKind::InvalidData
+                    || err.kind() == io::ErrorKind::InvalidInput =>
+            {
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Determines if player wants to play again.
+    ///
+    /// Fails if the input is interrupted or closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to read the answer from.
+    /// * `output` - The output to write the question to.
+    fn player_is_finished<I: Input, W: Write>(input: &mut I, output: &mut W) -> io::Result<bool> {
+        writeln!(output, "Are you finished playing (y/n)?:")?;
+
+        match input.read_line() {
+            Ok(player_input) => {
+                let temp_input = player_input.to_lowercase();
+
+                Ok(temp_input.trim() == "y" || temp_input.trim() == "yes")
+            }
+            Err(ref err)
+                if err.kind() == io::ErrorKind::InvalidData
+                    || err.kind() == io::ErrorKind::InvalidInput =>
+            {
+                Ok(false)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Resets the game.
+    ///
+    /// The obstacles are placed again, and with a handicap stone, the stone is placed again and
+    /// the bot is to move.
+    fn reset(&mut self) {
+        self.engine.current_turn = Turn::Player;
+        self.history.clear();
+        self.overwritten_tokens.clear();
+        self.last_bot_move = None;
+        self.mercy_declined = false;
+        self.engine.board = Engine::empty_board(self.engine.rows(), self.engine.cols());
+
+        for cell in self.config.obstacles.clone() {
+            self.place_obstacle(cell);
+        }
+
+        if let Some(cell) = self.config.handicap_stone {
+            self.engine.make_move(cell);
+        }
+    }
+}
+
+impl Default for Game {
+    fn default() -> Game {
+        Game::new()
+    }
+}
+
+/// Counts the terminal positions of every legal play sequence of the default game.
+///
+/// Returns the number of sequences ending in a win for X, a win for O, and a draw, in that
+/// order. The known totals are 131184, 77904, and 46080.
+///
+/// # Example
+///
+/// ```no_run
+/// use tic_tac_toe::game;
+///
+/// assert_eq!(game::count_outcomes(), (131184, 77904, 46080));
+/// ```
+pub fn count_outcomes() -> (u64, u64, u64) {
+    let mut counts = (0, 0, 0);
+
+    count_outcomes_from(&mut Engine::new(), &mut counts);
+
+    counts
+}
+
+/// Adds the terminal positions of every legal play sequence from an engine to the counts.
+///
+/// # Arguments
+///
+/// * `engine` - The engine to walk from, restored to its position once walked.
+/// * `counts` - The counts of wins for X, wins for O, and draws.
+fn count_outcomes_from(engine: &mut Engine, counts: &mut (u64, u64, u64)) {
+    if engine.game_is_won() {
+        match engine.get_next_turn() {
+            Turn::Player => counts.0 += 1,
+            Turn::Bot => counts.1 += 1,
+        }
+
+        return;
+    }
+
+    if engine.is_full() {
+        counts.2 += 1;
+
+        return;
+    }
+
+    for game_move in engine.available_moves() {
+        engine.make_move(game_move);
+
+        count_outcomes_from(engine, counts);
+
+        engine.unmake_move(game_move);
+    }
+}
+
+/// Counts the positions of every legal play sequence from an engine, including its own.
+///
+/// # Arguments
+///
+/// * `engine` - The engine to walk from, restored to its position once walked.
+fn count_nodes_from(engine: &mut Engine) -> u64 {
+    if engine.is_over() {
+        return 1;
+    }
+
+    let mut nodes = 1;
+
+    for game_move in engine.available_moves() {
+        engine.make_move(game_move);
+
+        nodes += count_nodes_from(engine);
+
+        engine.unmake_move(game_move);
+    }
+
+    nodes
+}
+
+/// Gets the process exit code of the result of a non-interactive run, for scripts: 0 for a
+/// draw, 1 for a win of the player or the first mover, and 2 for a win of the bot or the second
+/// mover.
+///
+/// # Arguments
+///
+/// * `outcome` - The outcome of the game for the player or the first mover.
+///
+/// # Example
+///
+/// ```
+/// use tic_tac_toe::game::{self, Outcome};
+///
+/// assert_eq!(game::exit_code(Outcome::Win), 1);
+/// ```
+pub fn exit_code(outcome: Outcome) -> i32 {
+    match outcome {
+        Outcome::Draw => 0,
+        Outcome::Win => 1,
+        Outcome::Loss => 2,
+    }
+}
+
+/// Verifies that alternating moves on the default board make up a complete game, e.g. to check
+/// a submitted game record.
+///
+/// Returns the outcome from the perspective of the first mover, or an error naming the first
+/// move that is illegal or comes after the game is over.
+///
+/// # Arguments
+///
+/// * `moves` - The moves of the game, in order, starting with the first mover.
+///
+/// # Example
+///
+/// ```
+/// use tic_tac_toe::game::{self, Outcome};
+///
+/// assert_eq!(game::verify_transcript(&[1, 4, 2, 5, 3]), Ok(Outcome::Win));
+/// assert!(game::verify_transcript(&[1, 1]).is_err());
+/// ```
+pub fn verify_transcript(moves: &[u32]) -> Result<Outcome, String> {
+    let mut engine = Engine::new();
+
+    for (index, &game_move) in moves.iter().enumerate() {
+        if engine.is_over() {
+            return Err(format!(
+                "Move {} ({}) comes after the game is over!",
+                index + 1,
+                game_move
+            ));
+        }
+
+        engine
+            .apply(game_move)
+            .map_err(|err| format!("Move {} ({}) is illegal: {}", index + 1, game_move, err))?;
+    }
+
+    if !engine.is_over() {
+        return Err(format!("The game is not over after {} moves!", moves.len()));
+    }
+
+    Ok(match engine.winner() {
+        Some(Turn::Player) => Outcome::Win,
+        Some(Turn::Bot) => Outcome::Loss,
+        None => Outcome::Draw,
+    })
+}
+
+/// Narrates a move in a spoken style, e.g. "O takes the center at 5, threatening to win at 1.",
+/// using the game after the move. This is the commentary shipped for `Game::with_commentator`.
+///
+/// # Arguments
+///
+/// * `game` - The game after the move.
+/// * `side` - The side that moved.
+/// * `game_move` - The move.
+///
+/// # Example
+///
+/// ```
+/// use tic_tac_toe::game::{self, Game, Turn};
+///
+/// let game = Game::from_notation("X---X-OO-").unwrap();
+///
+/// assert_eq!(
+///     game::commentary(&game, Turn::Player, 5),
+///     "X takes the center at 5, threatening to win at 9."
+/// );
+/// ```
+pub fn commentary(game: &Game, side: Turn, game_move: u32) -> String {
+    let (row, col) = game.engine.move_to_board_location(game_move);
+    let on_edge =
+        row == 0 || col == 0 || row + 1 == game.engine.rows() || col + 1 == game.engine.cols();
+    let place = if game.center_move() == Some(game_move) {
+        "the center"
+    } else if game.is_corner(game_move) {
+        "a corner"
+    } else if on_edge {
+        "an edge"
+    } else {
+        "a cell"
+    };
+    let narration = format!(
+        "{} takes {} at {}",
+        game.engine.turn_token(&side),
+        place,
+        game_move
+    );
+
+    if game.engine.winner() == Some(side) {
+        format!("{}, completing a line!", narration)
+    } else if game.near_wins(side) >= 2 {
+        format!("{}, creating a fork!", narration)
+    } else if let Some(threat) = game.engine.winning_move(side) {
+        format!("{}, threatening to win at {}.", narration, threat)
+    } else {
+        format!("{}.", narration)
+    }
+}
+
+/// Finds the first ply where two transcripts differ, e.g. to show where a game left the best
+/// line.
+///
+/// Returns the index of the ply, starting at 0, or `None` if the transcripts are the same or
+/// one is a prefix of the other.
+///
+/// # Arguments
+///
+/// * `a` - The moves of the first transcript, in order.
+/// * `b` - The moves of the second transcript, in order.
+///
+/// # Example
+///
+/// ```
+/// use tic_tac_toe::game;
+///
+/// assert_eq!(game::first_divergence(&[5, 1, 9], &[5, 3, 9]), Some(1));
+/// assert_eq!(game::first_divergence(&[5, 1], &[5, 1, 9]), None);
+/// ```
+pub fn first_divergence(a: &[u32], b: &[u32]) -> Option<usize> {
+    a.iter()
+        .zip(b)
+        .position(|(a_move, b_move)| a_move != b_move)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::sync::mpsc;
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_is_valid_move_allow_overwrite() {
+        let mut test_game = Game::new();
+
+        test_game.engine.apply(5).unwrap();
+
+        assert!(!test_game.engine.is_valid_move(5));
+        assert!(test_game.engine.apply(5).is_err());
+
+        test_game = test_game.with_allow_overwrite(true);
+
+        assert!(test_game.engine.is_valid_move(5));
+        assert!(!test_game.engine.is_valid_move(10));
+        assert!(test_game.engine.apply(5).is_ok());
+        assert_eq!(test_game.engine.board[1][1], "O");
+        assert_eq!(test_game.available_moves().len(), 8);
+
+        test_game = Game::new().with_allow_overwrite(true);
+        test_game.apply_move(5).unwrap();
+        test_game.apply_move(5).unwrap();
+        test_game.take_back_move();
+
+        assert_eq!(test_game.engine.board[1][1], "X");
+        assert_eq!(test_game.engine.current_turn, Turn::Bot);
+    }
+
+    #[test]
+    fn test_is_valid_move() {
+        let mut test_game = Game::new();
+
+        test_game.engine.board[2][2] = String::from("X");
+
+        for test_move in 1..9 {
+            assert!(test_game.engine.is_valid_move(test_move));
+        }
+
+        for bad_move in 10..20 {
+            assert!(!test_game.engine.is_valid_move(bad_move));
+        }
+    }
+
+    #[test]
+    fn test_validate_player_input() {
+        let mut test_game = Game::new();
+
+        test_game.engine.board[2][2] = String::from("X");
+
+        for test_move in 1..9 {
+            assert!(
+                test_game
+                    .validate_player_input(&test_move.to_string())
+                    .is_ok()
+            );
+        }
+
+        for bad_move in 10..20 {
+            assert!(
+                test_game
+                    .validate_player_input(&bad_move.to_string())
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_move_to_board_location() {
+        let test_game = Game::new();
+
+        assert_eq!(test_game.engine.move_to_board_location(1), (0, 0));
+        assert_eq!(test_game.engine.move_to_board_location(2), (0, 1));
+        assert_eq!(test_game.engine.move_to_board_location(3), (0, 2));
+
+        assert_eq!(test_game.engine.move_to_board_location(4), (1, 0));
+        assert_eq!(test_game.engine.move_to_board_location(5), (1, 1));
+        assert_eq!(test_game.engine.move_to_board_location(6), (1, 2));
+
+        assert_eq!(test_game.engine.move_to_board_location(7), (2, 0));
+        assert_eq!(test_game.engine.move_to_board_location(8), (2, 1));
+        assert_eq!(test_game.engine.move_to_board_location(9), (2, 2));
+    }
+
+    #[test]
+    fn test_get_next_turn() {
+        let mut test_game = Game::new();
+
+        assert_eq!(test_game.engine.get_next_turn(), Turn::Bot);
+
+        test_game.engine.current_turn = Turn::Bot;
+
+        assert_eq!(test_game.engine.get_next_turn(), Turn::Player);
+    }
+
+    #[test]
+    fn test_game_is_not_won() {
+        let test_game = Game::new();
+
+        assert!(!test_game.engine.game_is_won());
+    }
+
+    #[test]
+    fn test_game_is_won_row() {
+        let mut test_game = Game::new();
+
+        test_game.engine.board[1][0] = String::from("O");
+        test_game.engine.board[1][1] = String::from("O");
+        test_game.engine.board[1][2] = String::from("O");
+
+        assert!(test_game.engine.game_is_won());
+    }
+
+    #[test]
+    fn test_game_is_won_col() {
+        let mut test_game = Game::new();
+
+        test_game.engine.board[0][2] = String::from("X");
+        test_game.engine.board[1][2] = String::from("X");
+        test_game.engine.board[2][2] = String::from("X");
+
+        assert!(test_game.engine.game_is_won());
+    }
+
+    #[test]
+    fn test_game_is_won_diag() {
+        let mut test_game = Game::new();
+
+        test_game.engine.board[0][2] = String::from("X");
+        test_game.engine.board[1][1] = String::from("X");
+        test_game.engine.board[2][0] = String::from("X");
+
+        assert!(test_game.engine.game_is_won());
+    }
+
+    #[test]
+    fn test_from_board() {
+        let mut test_board = Game::new().engine.board;
+
+        test_board[0][0] = String::from("X");
+
+        assert!(Game::from_board(test_board.clone(), Turn::Bot).is_ok());
+
+        test_board[2][2] = String::from("Z");
+
+        assert!(Game::from_board(test_board, Turn::Bot).is_err());
+    }
+
+    #[test]
+    fn test_from_notation() {
+        let test_game = Game::from_notation("X---O---X").unwrap();
+
+        assert_eq!(test_game.engine.board[0][0], "X");
+        assert_eq!(test_game.engine.board[1][1], "O");
+        assert_eq!(test_game.engine.board[0][1], "2");
+        assert_eq!(test_game.engine.current_turn, Turn::Bot);
+
+        assert!(Game::from_notation("XX-------").is_err());
+        assert!(Game::from_notation("X---O--").is_err());
+    }
+
+    #[test]
+    fn test_apply_move_on_won_board() {
+        let mut test_game = Game::from_notation("XXXOO----").unwrap();
+
+        assert_eq!(
+            test_game.apply_move(6),
+            Err(String::from("Game is already over."))
+        );
+        assert_eq!(test_game.engine.board[1][2], "6");
+    }
+
+    #[test]
+    fn test_render_board_mark_last_move() {
+        let mut test_game = Game::new().with_mark_last_move(true);
+
+        test_game.apply_move(1).unwrap();
+        test_game.apply_move(5).unwrap();
+
+        let rendered = test_game.render_board();
+
+        assert!(rendered.contains("| X | 2 | 3 |"));
+        assert!(rendered.contains("| 4 |[O]| 6 |"));
+        assert_eq!(rendered.matches('[').count(), 1);
+    }
+
+    #[test]
+    fn test_available_moves() {
+        let test_game = Game::from_notation("X---O---X").unwrap();
+
+        assert_eq!(test_game.available_moves(), vec![2, 3, 4, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_ranked_moves() {
+        let test_game = Game::from_notation("XX-OO----").unwrap();
+        let ranked = test_game.ranked_moves();
+
+        assert_eq!(ranked.len(), 5);
+        assert_eq!(ranked[0], (3, Outcome::Win));
+
+        let outcomes: Vec<Outcome> = ranked.iter().map(|&(_, outcome)| outcome).collect();
+        let mut sorted_outcomes = outcomes.clone();
+
+        sorted_outcomes.sort_by_key(|outcome| match *outcome {
+            Outcome::Win => 0,
+            Outcome::Draw => 1,
+            Outcome::Loss => 2,
+        });
+
+        assert_eq!(outcomes, sorted_outcomes);
+    }
+
+    #[test]
+    fn test_ranked_moves_on_finished_game() {
+        let test_game = Game::from_notation("XXXOO----").unwrap();
+
+        assert!(test_game.ranked_moves().is_empty());
+    }
+
+    #[test]
+    fn test_drill_feedback() {
+        let test_game = Game::from_notation("----X----").unwrap();
+
+        let correct_feedback = test_game.drill_feedback(1);
+        let incorrect_feedback = test_game.drill_feedback(2);
+
+        assert_eq!(correct_feedback, "Correct! 1 keeps the draw.");
+        assert_eq!(
+            incorrect_feedback,
+            "Not optimal! 2 leads to a loss, but 1 leads to a draw."
+        );
+
+        let collapsed_game = test_game.with_collapse_symmetric(true);
+
+        assert_eq!(
+            collapsed_game.drill_feedback(9),
+            "Correct! 9 keeps the draw."
+        );
+    }
+
+    #[test]
+    fn test_game_is_send() {
+        fn assert_send<T: Send>() {}
+
+        assert_send::<Game>();
+    }
+
+    #[test]
+    fn test_hint_with_confidence() {
+        let test_game = Game::from_notation("----X----").unwrap();
+
+        let (hint_move, outcome) = test_game.hint_with_confidence().unwrap();
+
+        assert_eq!(outcome, Outcome::Draw);
+        assert!([1, 3, 7, 9].contains(&hint_move));
+        assert_eq!(
+            Game::from_notation("XOXXOOOXX")
+                .unwrap()
+                .hint_with_confidence(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_hint_with_confidence_lost_position() {
+        let test_game = Game::from_notation("X-X-O-X-O").unwrap();
+
+        let (hint_move, outcome) = test_game.hint_with_confidence().unwrap();
+
+        assert_eq!(outcome, Outcome::Loss);
+        assert!(test_game.available_moves().contains(&hint_move));
+    }
+
+    #[test]
+    fn test_with_handicap_stone() {
+        let mut test_game = Game::new().with_handicap_stone(5).unwrap();
+
+        assert_eq!(test_game.to_notation(), "----X----");
+        assert_eq!(test_game.engine.current_turn, Turn::Bot);
+        assert!(Game::new().with_handicap_stone(0).is_err());
+
+        test_game.engine.apply(1).unwrap();
+        test_game.reset();
+
+        assert_eq!(test_game.to_notation(), "----X----");
+        assert_eq!(test_game.engine.current_turn, Turn::Bot);
+        assert!(test_game.validate_state().is_ok());
+    }
+
+    #[test]
+    fn test_exit_code() {
+        assert_eq!(exit_code(Outcome::Draw), 0);
+        assert_eq!(exit_code(Outcome::Win), 1);
+        assert_eq!(exit_code(Outcome::Loss), 2);
+    }
+
+    #[test]
+    fn test_verify_transcript() {
+        assert_eq!(
+            verify_transcript(&[5, 1, 9, 3, 2, 8, 4, 6, 7]),
+            Ok(Outcome::Draw)
+        );
+        assert_eq!(verify_transcript(&[1, 4, 2, 5, 9, 6]), Ok(Outcome::Loss));
+        assert_eq!(
+            verify_transcript(&[1, 4, 2, 5, 3, 6]),
+            Err(String::from("Move 6 (6) comes after the game is over!"))
+        );
+        assert!(verify_transcript(&[1, 4, 4])
+            .unwrap_err()
+            .starts_with("Move 3 (4)"));
+        assert!(verify_transcript(&[1, 4, 2]).is_err());
+    }
+
+    #[test]
+    fn test_with_commentator() {
+        let comments = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&comments);
+        let mut test_game = Game::new().with_commentator(move |game, side, game_move| {
+            recorded
+                .lock()
+                .unwrap()
+                .push((game.to_notation(), side, game_move));
+        });
+
+        test_game.apply_move(5).unwrap();
+        test_game.apply_move(1).unwrap();
+
+        assert_eq!(
+            *comments.lock().unwrap(),
+            vec![
+                (String::from("----X----"), Turn::Player, 5),
+                (String::from("O---X----"), Turn::Bot, 1),
+            ]
+        );
+        assert!(test_game.snapshot().commentator.is_none());
+    }
+
+    #[test]
+    fn test_commentary() {
+        let game = |notation| Game::from_notation(notation).unwrap();
+
+        assert_eq!(
+            commentary(&game("-X-------"), Turn::Player, 2),
+            "X takes an edge at 2."
+        );
+        assert_eq!(
+            commentary(&game("XO--O---X"), Turn::Player, 9),
+            "X takes a corner at 9."
+        );
+        assert_eq!(
+            commentary(&game("XO--O-X-X"), Turn::Player, 7),
+            "X takes a corner at 7, creating a fork!"
+        );
+        assert_eq!(
+            commentary(&game("XXXOO----"), Turn::Player, 3),
+            "X takes a corner at 3, completing a line!"
+        );
+    }
+
+    #[test]
+    fn test_first_divergence() {
+        assert_eq!(first_divergence(&[5, 1, 9, 3], &[5, 1, 9, 3]), None);
+        assert_eq!(first_divergence(&[], &[5, 1]), None);
+        assert_eq!(first_divergence(&[5, 1, 9, 3], &[5, 1]), None);
+        assert_eq!(first_divergence(&[5, 1, 9, 3], &[5, 1, 9, 7, 3]), Some(3));
+        assert_eq!(first_divergence(&[1, 5], &[5, 1]), Some(0));
+    }
+
+    #[test]
+    fn test_can_hold() {
+        assert!(Game::from_notation("X---O---X").unwrap().can_hold());
+        assert!(!Game::from_notation("XO--X----").unwrap().can_hold());
+        assert!(!Game::from_notation("XXXOO----").unwrap().can_hold());
+        assert!(Game::from_notation("XOXXOOOXX").unwrap().can_hold());
+    }
+
+    #[test]
+    fn test_fork_moves() {
+        let test_game = Game::from_notation("XO--O---X").unwrap();
+
+        assert_eq!(test_game.fork_moves(Turn::Player), vec![7]);
+        assert!(Game::new().fork_moves(Turn::Player).is_empty());
+        assert!(Game::from_notation("X---O----")
+            .unwrap()
+            .fork_moves(Turn::Bot)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_control_score() {
+        assert_eq!(Game::new().control_score(), 0);
+        assert_eq!(
+            Game::from_notation("XX-OO----").unwrap().control_score(),
+            -1
+        );
+        assert_eq!(Game::from_notation("X---O---X").unwrap().control_score(), 1);
+        assert_eq!(Game::from_notation("----X----").unwrap().control_score(), 4);
+    }
+
+    #[test]
+    fn test_opening_name() {
+        let mut test_game = Game::new();
+
+        assert_eq!(test_game.opening_name(), None);
+
+        test_game.apply_move(1).unwrap();
+
+        assert_eq!(test_game.opening_name(), Some("Corner Game"));
+
+        test_game.apply_move(7).unwrap();
+
+        assert_eq!(
+            test_game.opening_name(),
+            Some("Corner Game, Adjacent Corner Reply")
+        );
+
+        test_game.apply_move(5).unwrap();
+
+        assert_eq!(test_game.opening_name(), None);
+    }
+
+    #[test]
+    fn test_random_fair_start() {
+        for seed in 0..10 {
+            let start = Game::random_fair_start(seed);
+            let (x_count, o_count) = start.token_counts();
+
+            assert!(x_count == o_count && (1..=2).contains(&x_count));
+            assert!(start.validate_state().is_ok());
+            assert!(!start.is_over());
+            assert!(start.history.is_empty());
+            assert_eq!(start.outcome_with_distance().unwrap().0, Outcome::Draw);
+            assert_eq!(
+                start.to_notation(),
+                Game::random_fair_start(seed).to_notation()
+            );
+        }
+    }
+
+    #[test]
+    fn test_moves_by_control() {
+        let opening = Game::new().moves_by_control();
+
+        assert_eq!(opening.len(), 9);
+        assert_eq!(opening[0], (5, 4));
+        assert_eq!(
+            opening[1..5]
+                .iter()
+                .map(|&(_, score)| score)
+                .collect::<Vec<i32>>(),
+            vec![3, 3, 3, 3]
+        );
+        assert_eq!(
+            Game::from_notation("----X----").unwrap().moves_by_control()[0],
+            (1, 1)
+        );
+    }
+
+    #[test]
+    fn test_safe_moves() {
+        let winning = Game::from_notation("XO-------").unwrap();
+        let lost = Game::from_notation("XO--X----").unwrap();
+
+        assert_eq!(winning.safe_moves(), vec![4, 5, 7]);
+        assert_eq!(lost.safe_moves(), lost.available_moves());
+        assert!(Game::from_notation("XXXOO----")
+            .unwrap()
+            .safe_moves()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_outcome_with_distance() {
+        let mate_in_one = Game::from_notation("XX-OO----").unwrap();
+        let mate_in_three = Game::from_notation("XOX-----O").unwrap();
+        let lost = Game::from_notation("XO--X----").unwrap();
+
+        let won = Game::from_notation("XXXOO----").unwrap();
+
+        assert_eq!(mate_in_one.outcome_with_distance(), Some((Outcome::Win, 1)));
+        assert_eq!(
+            mate_in_three.outcome_with_distance(),
+            Some((Outcome::Win, 3))
+        );
+        assert_eq!(lost.outcome_with_distance(), Some((Outcome::Loss, 4)));
+        assert_eq!(won.outcome_with_distance(), None);
+    }
+
+    #[test]
+    fn test_advantage() {
+        let advantage = |notation| Game::from_notation(notation).unwrap().advantage();
+
+        assert_eq!(advantage("XX-OO----"), 1.0);
+        assert!(advantage("XOX-----O") >= 0.8);
+        assert!(advantage("XO--X----") >= 0.5);
+        assert_eq!(advantage("XX-OO-X--"), -1.0);
+        assert!(advantage("XOXXOOOX-").abs() < 0.01);
+        assert_eq!(advantage("XXXOO----"), 1.0);
+        assert!(advantage("X---O---X").abs() <= 0.5);
+    }
+
+    #[test]
+    fn test_fastest_win() {
+        let test_game = Game::from_notation("XOOX-----").unwrap();
+
+        assert!(test_game.forced_wins().len() > 1);
+        assert_eq!(test_game.fastest_win(), Some((7, 1)));
+        assert_eq!(
+            Game::from_notation("XOX-----O").unwrap().fastest_win(),
+            Some((7, 3))
+        );
+        assert_eq!(
+            Game::from_notation("X---O----").unwrap().fastest_win(),
+            None
+        );
+        assert_eq!(
+            Game::from_notation("XXXOO----").unwrap().fastest_win(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_best_defensive_move() {
+        let test_game = Game::from_notation("XO--X----").unwrap();
+        let defensive_move = test_game.best_defensive_move().unwrap();
+        let plies_to_loss = |game_move| {
+            let (outcome, plies) = test_game.engine.clone().evaluate_move(game_move);
+
+            assert_eq!(outcome, Outcome::Loss);
+            plies
+        };
+
+        assert_eq!(defensive_move, 9);
+
+        for game_move in test_game.available_moves() {
+            assert!(plies_to_loss(game_move) <= plies_to_loss(defensive_move));
+        }
+
+        assert_eq!(
+            Game::from_notation("XX-OO----")
+                .unwrap()
+                .best_defensive_move(),
+            Some(3)
+        );
+        assert_eq!(
+            Game::from_notation("XXXOO----")
+                .unwrap()
+                .best_defensive_move(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_current_token() {
+        let mut test_game = Game::new();
+
+        assert_eq!(test_game.current_token(), "X");
+
+        test_game.apply_move(5).unwrap();
+
+        assert_eq!(test_game.current_token(), "O");
+    }
+
+    #[test]
+    fn test_engine_apply() {
+        let mut test_engine = Engine::new();
+
+        assert_eq!(test_engine.apply(5), Ok(()));
+        assert_eq!(test_engine.board[1][1], "X");
+        assert_eq!(test_engine.current_turn, Turn::Bot);
+        assert!(test_engine.apply(5).is_err());
+        assert!(test_engine.apply(10).is_err());
+        assert_eq!(test_engine.current_turn, Turn::Bot);
+
+        for &game_move in &[1, 4, 2, 7, 3] {
+            test_engine.apply(game_move).unwrap();
+        }
+
+        assert!(test_engine.is_over());
+        assert!(test_engine.apply(6).is_err());
+    }
+
+    #[test]
+    fn test_engine_winner() {
+        let mut test_engine = Engine::new();
+
+        for &game_move in &[1, 4, 2, 5, 9] {
+            test_engine.apply(game_move).unwrap();
+        }
+
+        assert_eq!(test_engine.winner(), None);
+        assert!(!test_engine.is_over());
+
+        test_engine.apply(6).unwrap();
+
+        assert_eq!(test_engine.winner(), Some(Turn::Bot));
+        assert!(test_engine.is_over());
+    }
+
+    #[test]
+    fn test_engine_available_moves() {
+        let mut test_engine = Engine::new();
+
+        for &game_move in &[5, 1, 9] {
+            test_engine.apply(game_move).unwrap();
+        }
+
+        assert_eq!(test_engine.available_moves(), vec![2, 3, 4, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_is_dead_draw() {
+        assert!(Game::from_notation("XOXXOOOX-").unwrap().is_dead_draw());
+        assert!(Game::from_notation("XOXXOOOXX").unwrap().is_dead_draw());
+        assert!(!Game::from_notation("XOX-O----").unwrap().is_dead_draw());
+        assert!(!Game::from_notation("XOXXOO-X-").unwrap().is_dead_draw());
+    }
+
+    #[test]
+    fn test_winning_direction() {
+        let direction = |notation| Game::from_notation(notation).unwrap().winning_direction();
+
+        assert_eq!(direction("O-OXXXO--"), Some(Direction::Row(1)));
+        assert_eq!(direction("OOX--XO-X"), Some(Direction::Col(2)));
+        assert_eq!(direction("XOOOX-OXX"), Some(Direction::DiagMain));
+        assert_eq!(direction("X-OXO-O-X"), Some(Direction::DiagAnti));
+        assert_eq!(direction("XO-------"), None);
+    }
+
+    #[test]
+    fn test_config() {
+        let orthogonal = WinDirections {
+            diagonals: false,
+            ..WinDirections::default()
+        };
+        let render_options = RenderOptions {
+            padding: 2,
+            row_spacing: 1,
+        };
+        let temp_path = |name| env::temp_dir().join(name);
+        let score_path = temp_path("tic_tac_toe_test_config.score");
+        let test_game = Game::new()
+            .with_size(4)
+            .unwrap()
+            .with_win_length(3)
+            .unwrap()
+            .with_toroidal(true)
+            .with_win_directions(orthogonal)
+            .unwrap()
+            .with_obstacles(&[6])
+            .unwrap()
+            .with_handicap_stone(1)
+            .unwrap()
+            .with_allow_overwrite(true)
+            .with_difficulty(Difficulty::Medium)
+            .with_adaptive_difficulty(true)
+            .with_tie_break(TieBreak::Corner)
+            .with_token_rule(TokenRule::FirstMoverIsX)
+            .with_seed(42)
+            .with_search_budget(Duration::from_millis(200))
+            .with_time_controls(Duration::from_secs(30), Duration::from_secs(1))
+            .with_round_limit(5)
+            .with_two_player(true)
+            .with_fog_of_war(true)
+            .with_copycat(true)
+            .with_teaching(true)
+            .with_challenge(true)
+            .with_mercy_rule(true)
+            .with_confirm_moves(true)
+            .with_bot_avoid_center_opening(true)
+            .with_scripted_bot(vec![2, 3])
+            .with_collapse_symmetric(true)
+            .with_think_out_loud(true)
+            .with_explain_moves(true)
+            .with_auto_continue(true)
+            .with_hold_result(true)
+            .with_speed_leaderboard(true)
+            .with_show_commands_hint(true)
+            .with_theme(Theme::HighContrast)
+            .with_render_options(render_options)
+            .with_show_empty_as(EmptyStyle::Dot)
+            .with_mark_last_move(true)
+            .with_highlight_legal(true)
+            .with_show_move_numbers_legend(true)
+            .with_interrupt_save(&temp_path("tic_tac_toe_test_config.save"))
+            .with_autosave(&temp_path("tic_tac_toe_test_config.autosave"))
+            .with_logfile(&temp_path("tic_tac_toe_test_config.log"))
+            .with_persistent_score(&score_path);
+
+        assert_eq!(
+            *test_game.config(),
+            Config {
+                rows: 4,
+                cols: 4,
+                win_length: 3,
+                toroidal: true,
+                win_directions: orthogonal,
+                obstacles: vec![6],
+                handicap_stone: Some(1),
+                allow_overwrite: cfg!(debug_assertions),
+                difficulty: Difficulty::Medium,
+                adaptive_difficulty: true,
+                tie_break: TieBreak::Corner,
+                token_rule: TokenRule::FirstMoverIsX,
+                seed: Some(42),
+                search_budget: Some(Duration::from_millis(200)),
+                time_controls: Some((Duration::from_secs(30), Duration::from_secs(1))),
+                round_limit: Some(5),
+                two_player: true,
+                fog_of_war: true,
+                copycat: true,
+                teaching: true,
+                challenge: true,
+                mercy_rule: true,
+                confirm_moves: true,
+                bot_avoid_center_opening: true,
+                bot_script: vec![2, 3],
+                collapse_symmetric: true,
+                think_out_loud: true,
+                explain_moves: true,
+                auto_continue: true,
+                hold_result: true,
+                speed_leaderboard: true,
+                show_commands_hint: true,
+                theme: Theme::HighContrast,
+                render_options,
+                show_empty_as: EmptyStyle::Dot,
+                mark_last_move: true,
+                highlight_legal: true,
+                show_move_numbers_legend: true,
+                interrupt_save_path: Some(temp_path("tic_tac_toe_test_config.save")),
+                autosave_path: Some(temp_path("tic_tac_toe_test_config.autosave")),
+                logfile_path: Some(temp_path("tic_tac_toe_test_config.log")),
+                score_path: Some(score_path.clone()),
+            }
+        );
+
+        let loaded_game = Game::from_notation("X------#--------").unwrap();
+
+        assert_eq!(loaded_game.config().rows, 4);
+        assert_eq!(loaded_game.config().win_length, 4);
+        assert_eq!(loaded_game.config().obstacles, vec![8]);
+
+        fs::remove_file(&score_path).unwrap();
+    }
+
+    #[test]
+    fn test_with_obstacles() {
+        let mut test_game = Game::new().with_obstacles(&[5]).unwrap();
+
+        assert!(!test_game.engine.is_valid_move(5));
+        assert!(test_game.apply_move(5).is_err());
+        assert!(!test_game.available_moves().contains(&5));
+        assert_eq!(test_game.engine.win_lines.len(), 4);
+
+        for &game_move in &[4, 1, 6, 2] {
+            test_game.apply_move(game_move).unwrap();
+        }
+
+        assert_eq!(test_game.engine.winning_move(Turn::Player), None);
+        assert_eq!(test_game.engine.winning_move(Turn::Bot), Some(3));
+
+        test_game.reset();
+
+        assert_eq!(test_game.engine.board[1][1], OBSTACLE);
+        assert_eq!(test_game.to_notation(), "----#----");
+        assert!(Game::new().with_obstacles(&[0]).is_err());
+        assert!(Game::new().with_obstacles(&[5, 5]).is_err());
+        assert!(test_game.with_handicap_stone(5).is_err());
+
+        let loaded_game = Game::from_notation("X---#----").unwrap();
+
+        assert_eq!(loaded_game.config.obstacles, vec![5]);
+        assert_eq!(loaded_game.engine.win_lines.len(), 4);
+        assert_eq!(loaded_game.to_notation(), "X---#----");
+
+        let resized_game = Game::new()
+            .with_size(4)
+            .unwrap()
+            .with_obstacles(&[16])
+            .unwrap()
+            .with_size(3)
+            .unwrap()
+            .with_handicap_stone(1)
+            .unwrap();
+
+        assert!(resized_game.config.obstacles.is_empty());
+        assert_eq!(resized_game.to_notation(), "X--------");
+    }
+
+    #[test]
+    fn test_with_win_directions() {
+        let orthogonal = WinDirections {
+            diagonals: false,
+            ..WinDirections::default()
+        };
+        let orthogonal_game = |notation| {
+            Game::from_notation(notation)
+                .unwrap()
+                .with_win_directions(orthogonal)
+                .unwrap()
+        };
+
+        assert!(!orthogonal_game("XOOOX---X").engine.game_is_won());
+        assert_eq!(orthogonal_game("XOOOX---X").engine.winner(), None);
+        assert!(orthogonal_game("XXXOO----").engine.game_is_won());
+        assert_eq!(
+            orthogonal_game("XX-OO----")
+                .engine
+                .winning_move(Turn::Player),
+            Some(3)
+        );
+        assert_eq!(
+            orthogonal_game("X---X-OO-")
+                .engine
+                .winning_move(Turn::Player),
+            None
+        );
+
+        let no_directions = WinDirections {
+            rows: false,
+            cols: false,
+            diagonals: false,
+        };
+
+        assert!(Game::new().with_win_directions(no_directions).is_err());
+    }
+
+    #[test]
+    fn test_winning_lines_all() {
+        let mut test_game = Game::new();
+
+        for &game_move in &[2, 5, 3, 6, 4, 8, 7, 9, 1] {
+            test_game.apply_move(game_move).unwrap();
+        }
+
+        assert_eq!(test_game.engine.winner(), Some(Turn::Player));
+        assert_eq!(
+            test_game.winning_lines_all(),
+            vec![vec![(0, 0), (0, 1), (0, 2)], vec![(0, 0), (1, 0), (2, 0)],]
+        );
+        assert!(Game::from_notation("X---O----")
+            .unwrap()
+            .winning_lines_all()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_line_report() {
+        let cells = |cells: &[&str]| cells.iter().map(|&cell| String::from(cell)).collect();
+        let report = Game::from_notation("X-O-XO--X").unwrap().line_report();
+
+        assert_eq!(report.len(), 8);
+        assert!(report.contains(&(String::from("row 0"), cells(&["X", "2", "O"]))));
+        assert!(report.contains(&(String::from("col 2"), cells(&["O", "O", "X"]))));
+        assert!(report.contains(&(String::from("diag main"), cells(&["X", "X", "X"]))));
+        assert!(report.contains(&(String::from("diag anti"), cells(&["O", "X", "7"]))));
+
+        let toroidal_report = Game::new().with_toroidal(true).line_report();
+
+        assert!(toroidal_report.contains(&(
+            String::from("diag main from (0, 1)"),
+            cells(&["2", "6", "7"])
+        )));
+
+        let large_report = Game::new()
+            .with_size(4)
+            .unwrap()
+            .with_win_length(3)
+            .unwrap()
+            .line_report();
+
+        assert!(
+            large_report.contains(&(String::from("row 0 from (0, 1)"), cells(&["2", "3", "4"])))
+        );
+    }
+
+    #[test]
+    fn test_with_dims() {
+        let mut test_game = Game::new().with_dims(2, 5, 3).unwrap();
+
+        assert_eq!(test_game.engine.move_to_board_location(10), (1, 4));
+        assert_eq!(test_game.engine.move_to_board_location(6), (1, 0));
+
+        for &game_move in &[2, 6, 3, 7, 4] {
+            test_game.apply_move(game_move).unwrap();
+        }
+
+        assert!(test_game.is_over());
+        assert_eq!(test_game.winning_direction(), Some(Direction::Row(0)));
+        assert!(test_game
+            .render_board()
+            .contains("| 1  | X  | X  | X  | 5  |\n"));
+        assert!(Game::new().with_dims(2, 5, 6).is_err());
+        assert!(Game::new().with_dims(0, 5, 3).is_err());
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let path = env::temp_dir().join("tic_tac_toe_test_snapshot.save");
+        let mut test_game = Game::from_notation("X---O----")
+            .unwrap()
+            .with_interrupt_save(&path)
+            .with_seed(3);
+
+        test_game.scoreboard.wins = 1;
+
+        let mut snapshot = test_game.snapshot();
+
+        snapshot.apply_move(9).unwrap();
+        snapshot.engine.board[0][1] = String::from("O");
+
+        assert_eq!(test_game.to_notation(), "X---O----");
+        assert_eq!(test_game.engine.current_turn, Turn::Player);
+        assert_eq!(test_game.history, Vec::<u32>::new());
+        assert_eq!(snapshot.to_notation(), "XO--O---X");
+        assert_eq!(snapshot.engine.current_turn, Turn::Bot);
+        assert_eq!(snapshot.scoreboard, Scoreboard::default());
+        assert_eq!(snapshot.config.interrupt_save_path, None);
+        assert_eq!(snapshot.config.seed, None);
+
+        let random_moves = |game: &Game| {
+            let mut snapshot = game.snapshot();
+
+            (0..5)
+                .map(|_| snapshot.random_move(&snapshot.available_moves()))
+                .collect::<Vec<u32>>()
+        };
+
+        assert_eq!(random_moves(&test_game), random_moves(&test_game));
+        assert_eq!(
+            random_moves(&test_game),
+            random_moves(&Game::from_notation("X---O----").unwrap().with_seed(3))
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let before = Game::new();
+        let mut after = Game::new();
+
+        after.apply_move(3).unwrap();
+
+        assert_eq!(
+            before.diff(&after.engine.board),
+            vec![(0, 2, String::from("X"))]
+        );
+        assert_eq!(
+            after.diff(&before.engine.board),
+            vec![(0, 2, String::from("3"))]
+        );
+        assert!(before.diff(&before.engine.board).is_empty());
+    }
+
+    #[test]
+    fn test_diff_ignores_placeholders() {
+        let before = Game::new();
+        let mut other_board = Game::new().engine.board;
+
+        other_board[0][0] = String::from("10");
+
+        assert!(before.diff(&other_board).is_empty());
+    }
+
+    #[test]
+    fn test_forced_wins() {
+        let test_game = Game::from_notation("X---O---X").unwrap();
+
+        assert!(test_game.forced_wins().is_empty());
+
+        let test_game = Game::from_notation("XX-OO----").unwrap();
+
+        assert_eq!(test_game.forced_wins(), vec![3]);
+    }
+
+    #[test]
+    fn test_best_move_tie_break() {
+        let mut test_game = Game::from_notation("-X-------").unwrap();
+
+        assert_eq!(test_game.best_move(&test_game.available_moves()), 1);
+
+        test_game = test_game.with_tie_break(TieBreak::Center);
+
+        assert_eq!(test_game.best_move(&test_game.available_moves()), 5);
+
+        test_game = Game::from_notation("X---O----")
+            .unwrap()
+            .with_tie_break(TieBreak::Corner);
+
+        assert_eq!(test_game.best_move(&test_game.available_moves()), 3);
+
+        test_game = test_game.with_tie_break(TieBreak::Random).with_seed(7);
+
+        let random_move = test_game.best_move(&test_game.available_moves());
+
+        assert!(test_game.engine.is_valid_move(random_move));
+        assert_eq!(
+            test_game
+                .clone()
+                .with_seed(7)
+                .best_move(&test_game.available_moves()),
+            random_move
+        );
+    }
+
+    #[test]
+    fn test_best_move_traps() {
+        let mut test_game = Game::from_notation("X-O--X---").unwrap();
+
+        assert_eq!(test_game.best_move(&test_game.available_moves()), 4);
+        assert_eq!(test_game.losing_replies(4), 0);
+        assert_eq!(test_game.losing_replies(5), 4);
+
+        test_game = test_game.with_tie_break(TieBreak::Traps);
+
+        assert_eq!(test_game.best_move(&test_game.available_moves()), 5);
+    }
+
+    #[test]
+    fn test_challenge_taunt() {
+        let test_game = Game::from_notation("XX-OO-X--").unwrap();
+
+        assert_eq!(test_game.challenge_taunt(9), Some("You've got me!"));
+        assert_eq!(test_game.challenge_taunt(6), Some("I'm winning!"));
+        assert_eq!(
+            Game::from_notation("X--------").unwrap().challenge_taunt(5),
+            Some("Let's call it a draw")
+        );
+        assert_eq!(Game::new().with_size(4).unwrap().challenge_taunt(1), None);
+    }
+
+    #[test]
+    fn test_validate_player_input_move_validator() {
+        let test_game = Game::new().with_move_validator(|game_move, _| {
+            if game_move == 5 {
+                Err(String::from("No center!"))
+            } else {
+                Ok(())
+            }
+        });
+
+        assert_eq!(
+            test_game.validate_player_input("5"),
+            Err(String::from("No center!"))
+        );
+        assert_eq!(test_game.validate_player_input("1"), Ok(1));
+        assert_eq!(
+            test_game.validate_player_input("10"),
+            Err(test_game.engine.invalid_move_message())
+        );
+    }
+
+    #[test]
+    fn test_ranked_moves_collapse_symmetric() {
+        let openings = Game::new().with_collapse_symmetric(true).ranked_moves();
+        let mut opening_moves: Vec<u32> =
+            openings.iter().map(|&(game_move, _)| game_move).collect();
+
+        opening_moves.sort();
+
+        assert_eq!(opening_moves, vec![1, 2, 5]);
+        assert!(openings
+            .iter()
+            .all(|&(_, outcome)| outcome == Outcome::Draw));
+
+        let test_game = Game::from_notation("X--------").unwrap();
+
+        assert_eq!(test_game.ranked_moves().len(), 8);
+        assert_eq!(
+            test_game.with_collapse_symmetric(true).ranked_moves(),
+            vec![
+                (5, Outcome::Draw),
+                (2, Outcome::Loss),
+                (3, Outcome::Loss),
+                (6, Outcome::Loss),
+                (9, Outcome::Loss),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_canonical_board() {
+        let test_game = Game::from_notation("-X-----O-").unwrap();
+        let rotated_game = Game::from_notation("---O-X---").unwrap();
+
+        assert_eq!(test_game.canonical_board(), rotated_game.canonical_board());
+        assert_ne!(
+            test_game.canonical_board(),
+            Game::from_notation("-X---O---").unwrap().canonical_board()
+        );
+    }
+
+    #[test]
+    fn test_position_eq() {
+        let mut test_game = Game::new().with_difficulty(Difficulty::Hard);
+
+        test_game.apply_move(1).unwrap();
+        test_game.apply_move(5).unwrap();
+
+        let same_game = Game::from_notation("X---O----")
+            .unwrap()
+            .with_fog_of_war(true);
+        let mut other_turn = same_game.clone();
+
+        other_turn.set_turn(Turn::Bot);
+
+        assert!(test_game.position_eq(&same_game));
+        assert!(!test_game.position_eq(&other_turn));
+        assert!(!test_game.position_eq(&Game::from_notation("X-------O").unwrap()));
+    }
+
+    #[test]
+    fn test_position_eq_symmetric() {
+        let test_game = Game::from_notation("XO--X----").unwrap();
+        let rotated_game = Game::from_notation("--X-XO---").unwrap();
+
+        assert!(!test_game.position_eq(&rotated_game));
+        assert!(test_game.position_eq_symmetric(&rotated_game));
+        assert!(!test_game.position_eq_symmetric(&Game::from_notation("X-O-X----").unwrap()));
+
+        let mut wide_game = Game::new().with_dims(2, 5, 3).unwrap();
+        let mut mirrored_game = wide_game.clone();
+
+        wide_game.apply_move(1).unwrap();
+        mirrored_game.apply_move(10).unwrap();
+
+        assert!(wide_game.position_eq_symmetric(&mirrored_game));
+    }
+
+    #[test]
+    fn test_immediate_win() {
+        let test_game = Game::from_notation("XX-OO-X--").unwrap();
+
+        assert_eq!(test_game.immediate_win(), Some(6));
+        assert_eq!(
+            Game::from_notation("XO-------").unwrap().immediate_win(),
+            None
+        );
+        assert_eq!(Game::new().immediate_win(), None);
+    }
+
+    #[test]
+    fn test_near_wins() {
+        let test_game = Game::from_notation("X-XO-O--X").unwrap();
+
+        assert_eq!(test_game.near_wins(Turn::Player), 2);
+        assert_eq!(test_game.near_wins(Turn::Bot), 1);
+        assert_eq!(Game::new().near_wins(Turn::Player), 0);
+        assert_eq!(
+            test_game.near_wins_summary(false),
+            "You were close! Near wins - X: 2, O: 1"
+        );
+        assert_eq!(test_game.near_wins_summary(true), "Near wins - X: 2, O: 1");
+    }
+
+    #[test]
+    fn test_export_decision_tree() {
+        let test_game = Game::from_notation("XOXOXO---").unwrap();
+        let dot = test_game.export_decision_tree(1);
+
+        assert!(dot.starts_with("digraph decision_tree {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains("    n0 [label=\"XOXOXO---\"];\n"));
+        assert!(dot.contains("    n2 [label=\"XOXOXO-X-\"];\n"));
+        assert!(dot.contains("    n0 -> n2 [label=\"8\"];\n"));
+        assert_eq!(dot.matches(" -> ").count(), 3);
+        assert_eq!(test_game.export_decision_tree(2).matches(" -> ").count(), 5);
+        assert_eq!(test_game.export_decision_tree(0).matches(" -> ").count(), 0);
+        assert_eq!(test_game.to_notation(), "XOXOXO---");
+    }
+
+    #[test]
+    fn test_generate_puzzle() {
+        for difficulty in &[Difficulty::Easy, Difficulty::Medium, Difficulty::Hard] {
+            let puzzle = Game::generate_puzzle(7, *difficulty);
+            let (_, outcome, plies) = puzzle.ranked_moves_with_plies()[0];
+
+            assert!(!puzzle.forced_wins().is_empty());
+            assert_eq!(outcome, Outcome::Win);
+            assert_eq!(plies, difficulty.plies_to_win());
+            assert_eq!(
+                puzzle.engine.board,
+                Game::generate_puzzle(7, *difficulty).engine.board
+            );
+        }
+    }
+
+    #[test]
+    fn test_practice_endgame() {
+        for &empty_cells in &[1, 3, 5, 9] {
+            let endgame = Game::practice_endgame(11, empty_cells).unwrap();
+
+            assert_eq!(endgame.available_moves().len(), empty_cells);
+            assert_eq!(endgame.engine.current_turn, Turn::Player);
+            assert_eq!(endgame.validate_state(), Ok(()));
+            assert_eq!(
+                endgame.engine.board,
+                Game::practice_endgame(11, empty_cells)
+                    .unwrap()
+                    .engine
+                    .board
+            );
+        }
+    }
+
+    #[test]
+    fn test_practice_endgame_invalid_empty_cells() {
+        assert!(Game::practice_endgame(11, 4).is_err());
+        assert!(Game::practice_endgame(11, 11).is_err());
+    }
+
+    #[test]
+    fn test_to_notation() {
+        let test_game = Game::from_notation("X---O---X").unwrap();
+
+        assert_eq!(test_game.to_notation(), "X---O---X");
+        assert_eq!(Game::new().to_notation(), "---------");
+    }
+
+    #[test]
+    fn test_to_ppm() {
+        let test_game = Game::from_notation("X---O----").unwrap();
+        let image = test_game.to_ppm();
+        let header = b"P6\n104 104\n255\n";
+
+        assert!(image.starts_with(header));
+        assert_eq!(image.len(), 104 * 104 * 3 + header.len());
+        assert_eq!(test_game.ppm_pixel(0, 0), [0, 0, 0]);
+        assert_eq!(test_game.ppm_pixel(17, 17), [220, 40, 40]);
+        assert_eq!(test_game.ppm_pixel(17 + 34, 17 + 34), [255, 255, 255]);
+        assert_eq!(test_game.ppm_pixel(17 + 34, 17 + 34 - 10), [40, 80, 220]);
+    }
+
+    #[test]
+    fn test_is_reachable() {
+        assert!(Game::new().is_reachable());
+        assert!(Game::from_notation("X---O---X").unwrap().is_reachable());
+        assert!(Game::from_notation("XXXOO----").unwrap().is_reachable());
+        assert!(Game::from_notation("XOXOXOXOX").unwrap().is_reachable());
+        assert!(!Game::from_notation("XXXOOO---").unwrap().is_reachable());
+        assert!(!Game::from_notation("XXXOOOX--").unwrap().is_reachable());
+    }
+
+    #[test]
+    fn test_ranked_moves_too_many_cells() {
+        let mut test_game = Game::new().with_size(4).unwrap();
+
+        test_game.apply_move(1).unwrap();
+
+        assert!(test_game.ranked_moves().is_empty());
+        assert_eq!(test_game.hint_with_confidence(), None);
+        assert!(test_game.forced_wins().is_empty());
+        assert!(test_game.safe_moves().is_empty());
+        assert!(test_game.can_hold());
+        assert_eq!(test_game.outcome_with_distance(), None);
+        assert_eq!(test_game.fastest_win(), None);
+        assert_eq!(test_game.best_defensive_move(), None);
+        assert!(test_game.advantage().abs() <= 0.5);
+        assert_eq!(test_game.searches.get(), 0);
+    }
+
+    #[test]
+    fn test_fill_ratio() {
+        let mut test_game = Game::new();
+
+        assert_eq!(test_game.fill_ratio(), 0.0);
+
+        for &game_move in &[1, 5, 9] {
+            test_game.apply_move(game_move).unwrap();
+        }
+
+        assert!((test_game.fill_ratio() - 0.33).abs() < 0.01);
+        assert_eq!(Game::from_notation("XOXXOOOXX").unwrap().fill_ratio(), 1.0);
+        assert_eq!(Game::new().with_size(4).unwrap().fill_ratio(), 0.0);
+
+        test_game = Game::new().with_obstacles(&[1, 9]).unwrap();
+
+        assert_eq!(test_game.fill_ratio(), 0.0);
+
+        test_game.apply_move(5).unwrap();
+
+        assert!((test_game.fill_ratio() - 1.0 / 7.0).abs() < 1e-9);
+        assert_eq!(Game::from_notation("XOXOX#OXO").unwrap().fill_ratio(), 1.0);
+    }
+
+    #[test]
+    fn test_reaching_sequences() {
+        assert_eq!(Game::new().reaching_sequences(), 1);
+        assert_eq!(
+            Game::from_notation("X---O---X")
+                .unwrap()
+                .reaching_sequences(),
+            2
+        );
+        assert_eq!(
+            Game::from_notation("XXXOO----")
+                .unwrap()
+                .reaching_sequences(),
+            12
+        );
+        assert_eq!(
+            Game::from_notation("XXX-OO-O-")
+                .unwrap()
+                .reaching_sequences(),
+            0
+        );
+        assert_eq!(
+            Game::from_notation("XXXOOO---")
+                .unwrap()
+                .reaching_sequences(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = env::temp_dir().join("tic_tac_toe_test_save_and_load.save");
+        let mut test_game = Game::new().with_obstacles(&[9]).unwrap();
+
+        test_game.apply_move(5).unwrap();
+        test_game.save(&path).unwrap();
+
+        let loaded_game = Game::load(&path).unwrap();
+
+        assert_eq!(loaded_game.engine.board, test_game.engine.board);
+        assert_eq!(loaded_game.engine.current_turn, Turn::Bot);
+        assert_eq!(loaded_game.config.obstacles, vec![9]);
+
+        test_game = Game::new().with_dims(2, 5, 3).unwrap().with_toroidal(true);
+        test_game.apply_move(7).unwrap();
+        test_game.save(&path).unwrap();
+
+        let loaded_game = Game::load(&path).unwrap();
+
+        assert_eq!(loaded_game.engine.board, test_game.engine.board);
+        assert_eq!(loaded_game.engine.win_length, 3);
+        assert!(loaded_game.engine.toroidal);
+
+        let orthogonal = WinDirections {
+            diagonals: false,
+            ..WinDirections::default()
+        };
+
+        test_game = Game::new().with_win_directions(orthogonal).unwrap();
+        test_game.apply_move(1).unwrap();
+        test_game.save(&path).unwrap();
+
+        let loaded_game = Game::load(&path).unwrap();
+
+        assert_eq!(loaded_game.engine.win_directions, orthogonal);
+        assert_eq!(loaded_game.engine.win_lines, test_game.engine.win_lines);
+
+        fs::write(&path, "-------X-\nbot\n").unwrap();
+
+        let loaded_game = Game::load(&path).unwrap();
+
+        assert_eq!(loaded_game.engine.board[2][1], "X");
+        assert_eq!(loaded_game.engine.win_length, 3);
+
+        fs::write(&path, "-------X-\nbot\n3 3 3 false player\n").unwrap();
+
+        let loaded_game = Game::load(&path).unwrap();
+
+        assert_eq!(loaded_game.engine.win_directions, WinDirections::default());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_autosave() {
+        let path = env::temp_dir().join("tic_tac_toe_test_autosave.save");
+        let mut test_game = Game::new().with_autosave(&path);
+
+        test_game.apply_move(5).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "----X----\nbot\n3 3 3 false player true true true\n"
+        );
+
+        test_game.apply_move(1).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "O---X----\nplayer\n3 3 3 false player true true true\n"
+        );
+        assert!(!env::temp_dir()
+            .join("tic_tac_toe_test_autosave.save.tmp")
+            .exists());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_save_and_load_binary() {
+        let path = env::temp_dir().join("tic_tac_toe_test_save_and_load_binary.bin");
+        let mut test_game = Game::new()
+            .with_size(5)
+            .unwrap()
+            .with_win_length(4)
+            .unwrap()
+            .with_toroidal(true);
+
+        test_game.apply_move(13).unwrap();
+        test_game.apply_move(25).unwrap();
+        test_game.save_binary(&path).unwrap();
+
+        assert_eq!(fs::read(&path).unwrap().len(), 17);
+
+        let loaded_game = Game::load_binary(&path).unwrap();
+
+        assert_eq!(loaded_game.engine.board, test_game.engine.board);
+        assert_eq!(loaded_game.engine.current_turn, Turn::Player);
+        assert_eq!(loaded_game.engine.win_length, 4);
+        assert!(loaded_game.engine.toroidal);
+
+        test_game = Game::new()
+            .with_dims(2, 5, 3)
+            .unwrap()
+            .with_obstacles(&[3])
+            .unwrap();
+        test_game.apply_move(7).unwrap();
+        test_game.save_binary(&path).unwrap();
+
+        let loaded_game = Game::load_binary(&path).unwrap();
+
+        assert_eq!(loaded_game.engine.board, test_game.engine.board);
+        assert_eq!(loaded_game.engine.current_turn, Turn::Bot);
+        assert_eq!(loaded_game.engine.win_length, 3);
+        assert_eq!(loaded_game.config.obstacles, vec![3]);
+        assert_eq!(loaded_game.engine.win_lines, test_game.engine.win_lines);
+
+        let orthogonal = WinDirections {
+            diagonals: false,
+            ..WinDirections::default()
+        };
+
+        test_game = Game::new().with_win_directions(orthogonal).unwrap();
+        test_game.save_binary(&path).unwrap();
+
+        let loaded_game = Game::load_binary(&path).unwrap();
+
+        assert_eq!(loaded_game.engine.win_directions, orthogonal);
+        assert_eq!(loaded_game.engine.win_lines, test_game.engine.win_lines);
+
+        fs::write(&path, b"TTTB\x01\x03\x03\x03\x00\x00\x00\x00\x00").unwrap();
+
+        let loaded_game = Game::load_binary(&path).unwrap();
+
+        assert_eq!(loaded_game.engine.win_directions, WinDirections::default());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_load_binary_wrong_magic() {
+        let path = env::temp_dir().join("tic_tac_toe_test_load_binary_wrong_magic.bin");
+
+        fs::write(&path, b"TTTX\x01\x03\x03\x03\x00\x00\x00\x00\x00").unwrap();
+
+        let err = Game::load_binary(&path).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "The file is not a binary save!");
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_play_from_file() {
+        let path = env::temp_dir().join("tic_tac_toe_test_play_from_file.txt");
+        let mut test_game = Game::new();
+        let mut output = Vec::new();
+
+        fs::write(&path, "1\n4\n2\n\n5\n3\n").unwrap();
+
+        assert_eq!(
+            test_game.play_from_file(&path, &mut output).unwrap(),
+            Some(Turn::Player)
+        );
+        assert_eq!(test_game.to_notation(), "XXXOO----");
+
+        let mut test_game = Game::new();
+
+        fs::write(&path, "1\n4\n1\n").unwrap();
+
+        let err = test_game.play_from_file(&path, &mut output).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("Line 3: "));
+
+        fs::write(&path, "1\n4\n2\n").unwrap();
+
+        let err = Game::new().play_from_file(&path, &mut output).unwrap_err();
+
+        assert_eq!(err.to_string(), "The game is not over after the last move!");
+
+        fs::write(&path, "1\n4\n2\n5\n3\n6\n").unwrap();
+
+        let err = Game::new().play_from_file(&path, &mut output).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Line 6: The move comes after the game is over!"
+        );
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_timing_stats() {
+        let times = [
+            Duration::from_millis(300),
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+        ];
+
+        assert_eq!(
+            Game::timing_stats(&times),
+            Some((Duration::from_millis(100), Duration::from_millis(200)))
+        );
+        assert_eq!(Game::timing_stats(&[]), None);
+    }
+
+    #[test]
+    fn test_session_summary_speed_leaderboard() {
+        let mut test_game = Game::new()
+            .with_two_player(true)
+            .with_speed_leaderboard(true);
+
+        test_game.move_times = vec![
+            (Turn::Player, Duration::from_millis(900)),
+            (Turn::Bot, Duration::from_millis(400)),
+            (Turn::Player, Duration::from_millis(300)),
+        ];
+
+        assert_eq!(
+            test_game.session_summary(),
+            "Final score - Wins: 0, Losses: 0, Draws: 0\nSpeed leaderboard:\n  \
+             1. O - fastest 400ms, average 400ms\n  2. X - fastest 300ms, average 600ms"
+        );
+    }
+
+    #[test]
+    fn test_session_summary_seed() {
+        assert!(!Game::new().session_summary().contains("seed"));
+        assert_eq!(
+            Game::new().with_seed(12345).session_summary(),
+            "Final score - Wins: 0, Losses: 0, Draws: 0\nSession seed: 12345"
+        );
+    }
+
+    #[test]
+    fn test_bot_difficulty_adaptive() {
+        let adaptive = |notation| {
+            Game::from_notation(notation)
+                .unwrap()
+                .with_difficulty(Difficulty::Medium)
+                .with_adaptive_difficulty(true)
+                .bot_difficulty()
+        };
+
+        assert_eq!(adaptive("X--------"), Difficulty::Easy);
+        assert_eq!(adaptive("XO-X-----"), Difficulty::Medium);
+        assert_eq!(adaptive("XOX-O----"), Difficulty::Medium);
+        assert_eq!(adaptive("XOX-O-X--"), Difficulty::Hard);
+        assert_eq!(
+            Game::from_notation("XOX-O-X--")
+                .unwrap()
+                .with_difficulty(Difficulty::Medium)
+                .bot_difficulty(),
+            Difficulty::Medium
+        );
+    }
+
+    #[test]
+    fn test_bot_moved_message() {
+        let started = Instant::now();
+        let moved = started + Duration::from_millis(12);
+
+        assert_eq!(
+            Game::bot_moved_message(5, moved.duration_since(started)),
+            "Bot moved at 5 (12ms)"
+        );
+        assert_eq!(
+            Game::bot_moved_message(9, Duration::from_micros(1500)),
+            "Bot moved at 9 (1ms)"
+        );
+    }
+
+    #[test]
+    fn test_get_bot_move_scripted() {
+        let mut test_game = Game::new()
+            .with_difficulty(Difficulty::Hard)
+            .with_scripted_bot(vec![3, 1]);
+
+        for &player_move in &[5, 7] {
+            test_game.apply_move(player_move).unwrap();
+
+            let bot_move = test_game.get_bot_move(&mut io::sink()).unwrap();
+
+            test_game.apply_move(bot_move).unwrap();
+        }
+
+        assert_eq!(test_game.bot_moves(), &[3, 1]);
+
+        test_game.apply_move(9).unwrap();
+
+        assert_eq!(test_game.get_bot_move(&mut io::sink()).unwrap(), 2);
+
+        let mut two_player_game = Game::new().with_two_player(true);
+
+        for &game_move in &[5, 1] {
+            two_player_game.apply_move(game_move).unwrap();
+        }
+
+        assert!(two_player_game.bot_moves().is_empty());
+    }
+
+    #[test]
+    fn test_get_bot_move_copycat() {
+        let mut test_game = Game::new()
+            .with_difficulty(Difficulty::Hard)
+            .with_copycat(true);
+
+        test_game.apply_move(1).unwrap();
+
+        assert_eq!(test_game.get_bot_move(&mut io::sink()).unwrap(), 9);
+
+        let mut test_game = Game::new()
+            .with_difficulty(Difficulty::Hard)
+            .with_copycat(true);
+
+        test_game.apply_move(5).unwrap();
+
+        assert_eq!(test_game.mirrored_move(), None);
+        assert!([1, 3, 7, 9].contains(&test_game.get_bot_move(&mut io::sink()).unwrap()));
+    }
+
+    #[test]
+    fn test_evaluation_cache() {
+        let mut test_game = Game::from_notation("X---O----")
+            .unwrap()
+            .with_difficulty(Difficulty::Hard)
+            .with_challenge(true);
+
+        let bot_move = test_game.get_bot_move(&mut io::sink()).unwrap();
+
+        test_game.safe_moves();
+        test_game.outcome_with_distance();
+
+        assert_eq!(test_game.searches.get(), 1);
+
+        test_game.apply_move(bot_move).unwrap();
+        test_game.can_hold();
+        test_game.can_hold();
+
+        assert_eq!(test_game.searches.get(), 2);
+
+        let orthogonal = WinDirections {
+            diagonals: false,
+            ..WinDirections::default()
+        };
+
+        test_game = Game::from_notation("XO-OX----").unwrap();
+
+        assert_eq!(test_game.fastest_win(), Some((9, 1)));
+
+        test_game = test_game.with_win_directions(orthogonal).unwrap();
+
+        assert_eq!(test_game.fastest_win(), None);
+    }
+
+    #[test]
+    fn test_choose_bot_move_reason() {
+        let mut test_game = Game::from_notation("XX-O-----")
+            .unwrap()
+            .with_difficulty(Difficulty::Medium);
+
+        assert_eq!(test_game.choose_bot_move(), (3, MoveReason::Block));
+        assert_eq!(
+            Game::move_reason_message(3, MoveReason::Block),
+            "Bot's reason: blocking your threat at 3"
+        );
+        assert_eq!(
+            Game::from_notation("XX-OO-X--")
+                .unwrap()
+                .with_difficulty(Difficulty::Medium)
+                .choose_bot_move(),
+            (6, MoveReason::Win)
+        );
+
+        test_game = Game::new()
+            .with_difficulty(Difficulty::Hard)
+            .with_tie_break(TieBreak::Center)
+            .with_explain_moves(true);
+        test_game.engine.current_turn = Turn::Bot;
+
+        assert_eq!(test_game.choose_bot_move(), (5, MoveReason::Center));
+
+        let mut output = Vec::new();
+
+        test_game.get_bot_move(&mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Bot played moved at: 5\nBot's reason: playing center at 5\n"
+        );
+    }
+
+    #[test]
+    fn test_choose_bot_move_teaching() {
+        let play_out = |mut test_game: Game| {
+            while !test_game.is_over() {
+                let game_move = match test_game.engine.current_turn {
+                    Turn::Player => test_game.safe_moves()[0],
+                    Turn::Bot => test_game.choose_bot_move().0,
+                };
+
+                test_game.apply_move(game_move).unwrap();
+            }
+
+            test_game.engine.winner()
+        };
+
+        for seed in 0..5 {
+            let test_game = Game::new()
+                .with_difficulty(Difficulty::Hard)
+                .with_teaching(true)
+                .with_seed(seed);
+
+            assert_eq!(play_out(test_game), None);
+        }
+
+        let mut blundered_game = Game::from_notation("XX-OO-X--")
+            .unwrap()
+            .with_difficulty(Difficulty::Hard)
+            .with_teaching(true);
+
+        assert_eq!(blundered_game.fastest_win(), Some((6, 1)));
+
+        let (teaching_move, reason) = blundered_game.choose_bot_move();
+
+        assert_ne!(teaching_move, 6);
+        assert_eq!(reason, MoveReason::Teaching);
+        assert_eq!(play_out(blundered_game), None);
+    }
+
+    #[test]
+    fn test_last_decision() {
+        let mut test_game = Game::from_notation("X--------")
+            .unwrap()
+            .with_difficulty(Difficulty::Medium)
+            .with_seed(3);
+
+        let bot_move = test_game.get_bot_move(&mut io::sink()).unwrap();
+        let decision = test_game.last_decision().unwrap();
+
+        assert_eq!(decision.chosen, bot_move);
+        assert_eq!(
+            decision.reason,
+            format!("playing at random at {}", bot_move)
+        );
+        assert_eq!(
+            decision
+                .candidates
+                .iter()
+                .map(|&(game_move, _)| game_move)
+                .collect::<Vec<u32>>(),
+            test_game.available_moves()
+        );
+        assert!(decision.candidates.contains(&(5, Some(Outcome::Draw))));
+        assert!(decision.candidates.contains(&(2, Some(Outcome::Loss))));
+    }
+
+    #[test]
+    fn test_limited_alpha_beta_horizon() {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let horizon_score = |game_move| {
+            let mut engine = Game::new().with_size(4).unwrap().engine;
+
+            engine.make_move(game_move);
+            engine.limited_alpha_beta(1, 1, -i32::MAX, i32::MAX, deadline)
+        };
+
+        assert_eq!(horizon_score(6), Some((-29, true)));
+        assert_eq!(horizon_score(2), Some((-19, true)));
+
+        let test_game = Game::from_notation("X---O---X").unwrap();
+
+        assert_eq!(test_game.engine.horizon_score(1), -12);
+        assert_eq!(test_game.engine.horizon_score(99), 0);
+    }
+
+    #[test]
+    fn test_search_move_budget() {
+        let mut large_game = Game::new()
+            .with_size(4)
+            .unwrap()
+            .with_search_budget(Duration::from_millis(50));
+
+        large_game.apply_move(1).unwrap();
+
+        let (large_move, _) = large_game.search_move(&large_game.available_moves());
+
+        assert!(large_game.engine.is_valid_move(large_move));
+
+        for notation in &["XX-OO----", "X---O---X", "XO-------"] {
+            let mut test_game = Game::from_notation(notation)
+                .unwrap()
+                .with_search_budget(Duration::from_secs(10));
+            let best_score = {
+                let ranked = test_game.ranked_moves_with_plies();
+
+                Game::outcome_score(ranked[0].1, ranked[0].2)
+            };
+            let (budgeted_move, reason) = test_game.search_move(&test_game.available_moves());
+            let (outcome, plies) = test_game.engine.clone().evaluate_move(budgeted_move);
+
+            assert_eq!(reason, MoveReason::Search);
+            assert_eq!(Game::outcome_score(outcome, plies), best_score);
+        }
+    }
+
+    #[test]
+    fn test_get_bot_move_seeded() {
+        let mut test_game = Game::new().with_seed(12345);
+        let mut replayed_game = test_game.clone();
+
+        test_game.set_turn(Turn::Bot);
+        replayed_game.set_turn(Turn::Bot);
+
+        assert_eq!(
+            test_game.get_bot_move(&mut io::sink()).unwrap(),
+            replayed_game.get_bot_move(&mut io::sink()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_resume_or_new() {
+        let path = env::temp_dir().join("tic_tac_toe_test_resume_or_new.save");
+        let mut test_game = Game::new();
+
+        test_game.apply_move(5).unwrap();
+        test_game.save(&path).unwrap();
+
+        let (resumed_game, message) = Game::resume_or_new_with_message(&path);
+
+        assert_eq!(resumed_game.engine.board, test_game.engine.board);
+        assert_eq!(resumed_game.engine.current_turn, Turn::Bot);
+        assert!(message.starts_with("Resumed"));
+        assert!(!path.exists());
+
+        fs::write(&path, "not a save").unwrap();
+
+        let (new_game, message) = Game::resume_or_new_with_message(&path);
+
+        assert_eq!(new_game.engine.board, Game::new().engine.board);
+        assert!(message.starts_with("Could not resume"));
+
+        fs::remove_file(&path).unwrap();
+
+        let (new_game, message) = Game::resume_or_new_with_message(&path);
+
+        assert_eq!(new_game.engine.board, Game::new().engine.board);
+        assert_eq!(new_game.engine.current_turn, Turn::Player);
+        assert!(message.starts_with("No saved game"));
+    }
+
+    #[test]
+    fn test_on_interrupt() {
+        let path = env::temp_dir().join("tic_tac_toe_test_on_interrupt.save");
+        let mut test_game = Game::new();
+
+        assert_eq!(
+            test_game.on_interrupt(),
+            "\nInterrupted! Final score - Wins: 0, Losses: 0, Draws: 0"
+        );
+
+        test_game = test_game.with_interrupt_save(&path);
+        test_game.scoreboard.wins = 2;
+        test_game.scoreboard.draws = 1;
+        test_game.apply_move(1).unwrap();
+
+        assert_eq!(
+            test_game.on_interrupt(),
+            format!(
+                "\nInterrupted! Final score - Wins: 2, Losses: 0, Draws: 1\nGame saved to {}",
+                path.display()
+            )
+        );
+        assert_eq!(
+            Game::load(&path).unwrap().engine.board,
+            test_game.engine.board
+        );
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_get_bot_move_think_out_loud() {
+        let mut test_game = Game::new();
+        let mut output = Vec::new();
+
+        test_game.engine.current_turn = Turn::Bot;
+
+        let bot_move = test_game.get_bot_move(&mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!("Bot played moved at: {}\n", bot_move)
+        );
+
+        test_game = test_game.with_think_out_loud(true);
+        output = Vec::new();
+
+        let bot_move = test_game.get_bot_move(&mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.starts_with("Bot is thinking...\n"));
+        assert!(output.contains(&format!("Bot moved at {} (", bot_move)));
+    }
+
+    #[test]
+    fn test_get_bot_move_avoid_center_opening() {
+        let mut test_game = Game::new().with_bot_avoid_center_opening(true);
+
+        test_game.engine.current_turn = Turn::Bot;
+
+        for _ in 0..100 {
+            assert_ne!(test_game.get_bot_move(&mut io::sink()).unwrap(), 5);
+        }
+
+        test_game.engine.board = Game::from_notation("XOXO-OXOX").unwrap().engine.board;
+
+        assert_eq!(test_game.get_bot_move(&mut io::sink()).unwrap(), 5);
+
+        let mut hard_game = Game::new()
+            .with_difficulty(Difficulty::Hard)
+            .with_tie_break(TieBreak::Center)
+            .with_bot_avoid_center_opening(true);
+
+        hard_game.engine.current_turn = Turn::Bot;
+
+        assert_ne!(hard_game.get_bot_move(&mut io::sink()).unwrap(), 5);
+
+        let mut teaching_game = Game::new()
+            .with_teaching(true)
+            .with_bot_avoid_center_opening(true);
+
+        teaching_game.engine.current_turn = Turn::Bot;
+
+        for _ in 0..10 {
+            assert_ne!(teaching_game.get_bot_move(&mut io::sink()).unwrap(), 5);
+        }
+
+        let mut obstacle_game = Game::new()
+            .with_obstacles(&[1])
+            .unwrap()
+            .with_bot_avoid_center_opening(true);
+
+        obstacle_game.engine.current_turn = Turn::Bot;
+
+        for _ in 0..100 {
+            assert_ne!(obstacle_game.get_bot_move(&mut io::sink()).unwrap(), 5);
+        }
+    }
+
+    /// Searches the game tree with minimax, without pruning.
+    ///
+    /// Returns the outcome for the side to move, the number of plies until the game ends under
+    /// optimal play, and the number of nodes visited.
+    fn minimax_unpruned(game: &mut Game) -> (Outcome, u32, u64) {
+        if game.engine.game_is_won() {
+            return (Outcome::Loss, 0, 1);
+        }
+
+        if game.engine.is_full() {
+            return (Outcome::Draw, 0, 1);
+        }
+
+        let mut best: Option<(Outcome, u32)> = None;
+        let mut nodes = 1;
+
+        for game_move in game.available_moves() {
+            game.engine.make_move(game_move);
+
+            let (outcome, plies, child_nodes) = minimax_unpruned(game);
+
+            game.engine.unmake_move(game_move);
+
+            let (outcome, plies) = (outcome.flip(), plies + 1);
+            let is_better = match best {
+                Some((best_outcome, best_plies)) => {
+                    Game::outcome_score(outcome, plies)
+                        > Game::outcome_score(best_outcome, best_plies)
+                }
+                None => true,
+            };
+
+            if is_better {
+                best = Some((outcome, plies));
+            }
+
+            nodes += child_nodes;
+        }
+
+        let (outcome, plies) = best.unwrap();
+
+        (outcome, plies, nodes)
+    }
+
+    #[test]
+    fn test_alpha_beta_matches_unpruned() {
+        for notation in &[
+            "X---O----",
+            "-X-------",
+            "XX-OO----",
+            "X-O-X--O-",
+            "XO-OX----",
+        ] {
+            let mut test_game = Game::from_notation(notation).unwrap();
+
+            for game_move in test_game.available_moves() {
+                let (outcome, plies) = test_game.engine.evaluate_move(game_move);
+
+                test_game.engine.make_move(game_move);
+
+                let (unpruned_outcome, unpruned_plies, _) = minimax_unpruned(&mut test_game);
+
+                test_game.engine.unmake_move(game_move);
+
+                assert_eq!(outcome, unpruned_outcome.flip());
+
+                if outcome != Outcome::Draw {
+                    assert_eq!(plies, unpruned_plies + 1);
+                }
             }
-            Err(_) => false
+
+            let (_, pruned_nodes) = test_game.engine.alpha_beta(0, -i32::MAX, i32::MAX);
+            let (_, _, unpruned_nodes) = minimax_unpruned(&mut test_game);
+
+            assert!(pruned_nodes < unpruned_nodes);
+        }
+    }
+
+    #[test]
+    fn test_subtree_size() {
+        assert_eq!(Game::new().subtree_size(), 549946);
+        assert_eq!(Game::from_notation("XXXOO----").unwrap().subtree_size(), 1);
+    }
+
+    #[test]
+    fn test_count_outcomes() {
+        assert_eq!(count_outcomes(), (131184, 77904, 46080));
+    }
+
+    #[test]
+    fn test_game_is_won_toroidal_diag() {
+        let mut test_game = Game::new();
+
+        test_game.engine.board[0][1] = String::from("X");
+        test_game.engine.board[1][2] = String::from("X");
+        test_game.engine.board[2][0] = String::from("X");
+
+        assert!(!test_game.engine.game_is_won());
+
+        test_game = test_game.with_toroidal(true);
+
+        assert!(test_game.engine.game_is_won());
+
+        test_game.engine.board = Game::new().engine.board;
+        test_game.engine.board[0][1] = String::from("O");
+        test_game.engine.board[1][0] = String::from("O");
+        test_game.engine.board[2][2] = String::from("O");
+
+        assert!(test_game.engine.game_is_won());
+    }
+
+    #[test]
+    fn test_lines() {
+        let mut test_game = Game::new();
+
+        assert_eq!(test_game.engine.lines().len(), 8);
+
+        test_game.engine.toroidal = true;
+
+        assert_eq!(test_game.engine.lines().len(), 12);
+
+        test_game = Game::new()
+            .with_size(4)
+            .unwrap()
+            .with_win_length(3)
+            .unwrap();
+
+        assert_eq!(test_game.engine.lines().len(), 24);
+    }
+
+    #[test]
+    fn test_lines_toroidal_narrow_board() {
+        let mut test_game = Game::new().with_dims(2, 5, 3).unwrap().with_toroidal(true);
+
+        assert!(test_game.engine.lines().iter().all(|line| {
+            let mut cells = line.clone();
+
+            cells.sort();
+            cells.dedup();
+
+            cells.len() == line.len()
+        }));
+
+        for &game_move in &[1, 3, 6] {
+            test_game.engine.apply(game_move).unwrap();
         }
+
+        assert!(!test_game.engine.game_is_won());
+        assert!(test_game.winning_lines_all().is_empty());
+    }
+
+    #[test]
+    fn test_set_turn() {
+        let mut test_game = Game::new();
+
+        test_game.set_turn(Turn::Bot);
+
+        assert_eq!(test_game.engine.current_turn, Turn::Bot);
+
+        test_game
+            .play_turn(&mut io::empty(), &mut Vec::new())
+            .unwrap();
+
+        assert_eq!(
+            test_game
+                .engine
+                .board
+                .iter()
+                .flatten()
+                .filter(|cell| *cell == "O")
+                .count(),
+            1
+        );
+        assert_eq!(
+            test_game
+                .engine
+                .board
+                .iter()
+                .flatten()
+                .filter(|cell| *cell == "X")
+                .count(),
+            0
+        );
     }
 
-    /// Resets the game.
-    fn reset(&mut self) {
-        self.current_turn = Turn::Player;
-        self.board = vec![
-            vec![String::from("1"), String::from("2"), String::from("3")],
-            vec![String::from("4"), String::from("5"), String::from("6")],
-            vec![String::from("7"), String::from("8"), String::from("9")],
-        ];
+    #[test]
+    fn test_render_board_fog_of_war() {
+        let mut test_game = Game::new().with_fog_of_war(true);
+
+        test_game.set_turn(Turn::Bot);
+        test_game.apply_move(5).unwrap();
+
+        assert!(test_game.render_board().contains("| 4 | ? | 6 |"));
+
+        test_game.apply_move(1).unwrap();
+
+        let rendered = test_game.render_board();
+
+        assert!(rendered.contains("| X | 2 | 3 |"));
+        assert!(rendered.contains("| 4 | O | 6 |"));
+        assert!(!rendered.contains('?'));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_render_to() {
+        let mut test_game = Game::new();
+        let mut rendered = Vec::new();
+
+        test_game.apply_move(1).unwrap();
+        test_game.apply_move(5).unwrap();
+        test_game.render_to(&mut rendered).unwrap();
+
+        let expected = "\n+---+---+---+\n\
+                        | X | 2 | 3 |\n\
+                        +---+---+---+\n\
+                        | 4 | O | 6 |\n\
+                        +---+---+---+\n\
+                        | 7 | 8 | 9 |\n\
+                        +---+---+---+\n\n";
+
+        assert_eq!(rendered, expected.as_bytes());
+    }
 
     #[test]
-    fn test_is_valid_move() {
+    fn test_replay_step() {
         let mut test_game = Game::new();
+        let expected = ["----X----", "O---X----", "O---X---X", "O---X-O-X"];
 
-        test_game.board[2][2] = String::from("X");
+        for (&game_move, &notation) in [5, 1, 9, 7].iter().zip(expected.iter()) {
+            let rendered = test_game.replay_step(game_move).unwrap();
 
-        for test_move in 1..9 {
-            assert!(test_game.is_valid_move(test_move));
+            assert_eq!(test_game.to_notation(), notation);
+            assert_eq!(rendered, test_game.render_board());
         }
 
-        for bad_move in 10..20 {
-            assert!(!test_game.is_valid_move(bad_move));
-        }
+        assert!(test_game.replay_step(5).is_err());
     }
 
     #[test]
-    fn test_validate_player_input() {
+    fn test_replay_interactive() {
         let mut test_game = Game::new();
+        let mut input = io::Cursor::new("\n");
+        let mut output = Vec::new();
 
-        test_game.board[2][2] = String::from("X");
+        test_game
+            .replay_interactive(&[5, 1], &mut input, &mut output)
+            .unwrap();
 
-        for test_move in 1..9 {
-            assert!(
-                test_game
-                    .validate_player_input(&test_move.to_string())
-                    .is_ok()
-            );
+        let output = String::from_utf8(output).unwrap();
+
+        assert_eq!(output.matches("Press enter").count(), 1);
+        assert_eq!(output.matches("+---+---+---+").count(), 8);
+        assert_eq!(test_game.to_notation(), "O---X----");
+    }
+
+    #[test]
+    fn test_render_board_highlight_legal() {
+        let mut test_game = Game::new().with_highlight_legal(true);
+
+        test_game.apply_move(1).unwrap();
+        test_game.apply_move(5).unwrap();
+
+        let rendered = test_game.render_board();
+
+        assert!(rendered.contains("| X |(2)|(3)|"));
+        assert!(rendered.contains("|(4)| O |(6)|"));
+        assert!(rendered.contains("|(7)|(8)|(9)|"));
+    }
+
+    #[test]
+    fn test_render_board_render_options() {
+        let mut test_game = Game::new().with_render_options(RenderOptions {
+            padding: 3,
+            row_spacing: 1,
+        });
+
+        test_game.engine.board[0][0] = String::from("X");
+
+        let rendered = test_game.render_board();
+
+        assert!(rendered.contains("\n+-------+-------+-------+\n|       |       |       |\n"));
+        assert!(rendered.contains("|   X   |   2   |   3   |\n"));
+        assert_eq!(
+            Game::new()
+                .with_render_options(RenderOptions::default())
+                .render_board(),
+            Game::new().render_board()
+        );
+    }
+
+    #[test]
+    fn test_render_board_theme() {
+        let mut test_game = Game::new()
+            .with_mark_last_move(true)
+            .with_theme(Theme::Monochrome);
+
+        test_game.apply_move(1).unwrap();
+        test_game.apply_move(5).unwrap();
+
+        assert!(!test_game.render_board().contains('\x1b'));
+
+        let rendered = test_game.with_theme(Theme::HighContrast).render_board();
+
+        assert!(rendered.contains("\x1b[97m|\x1b[0m \x1b[1;91mX\x1b[0m \x1b[97m|\x1b[0m"));
+        assert!(rendered.contains("\x1b[1;93m[\x1b[0m\x1b[1;96mO\x1b[0m\x1b[1;93m]\x1b[0m"));
+        assert!(rendered.contains("\x1b[97m+---+---+---+\x1b[0m"));
+        assert_eq!("High-Contrast".parse::<Theme>(), Ok(Theme::HighContrast));
+    }
+
+    #[test]
+    fn test_render_board_show_empty_as() {
+        let mut test_game = Game::new().with_show_empty_as(EmptyStyle::Blank);
+
+        test_game.apply_move(1).unwrap();
+        test_game.apply_move(5).unwrap();
+
+        assert!(test_game.render_board().contains("| X |   |   |"));
+        assert!(test_game.render_board().contains("|   | O |   |"));
+
+        test_game = test_game.with_show_empty_as(EmptyStyle::Dot);
+
+        assert!(test_game.render_board().contains("| X | . | . |"));
+        assert!(test_game.render_board().contains("| . | O | . |"));
+
+        test_game = test_game.with_show_empty_as(EmptyStyle::Number);
+
+        assert!(test_game.render_board().contains("| X | 2 | 3 |"));
+
+        test_game.apply_move(9).unwrap();
+
+        assert_eq!(test_game.to_notation(), "X---O---X");
+    }
+
+    #[test]
+    fn test_render_board_move_numbers_legend() {
+        let mut test_game = Game::new().with_show_move_numbers_legend(true);
+
+        test_game.apply_move(1).unwrap();
+        test_game.apply_move(5).unwrap();
+
+        let rendered = test_game.render_board();
+        let legend = &rendered[rendered.find("Move numbers:").unwrap()..];
+
+        assert!(rendered.contains("| 4 | O | 6 |"));
+        assert!(legend.contains("| 1 | 2 | 3 |"));
+        assert!(legend.contains("| 4 | 5 | 6 |"));
+        assert!(legend.contains("| 7 | 8 | 9 |"));
+        assert!(!Game::new().render_board().contains("Move numbers:"));
+    }
+
+    #[test]
+    fn test_get_player_move_too_long() {
+        let mut test_game = Game::new();
+        let mut input = io::Cursor::new(format!("{}\n5\n", "5".repeat(1 << 20)));
+        let mut output = Vec::new();
+
+        assert_eq!(
+            test_game.get_player_move(&mut input, &mut output).unwrap(),
+            Some(5)
+        );
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("Input is too long, try again!"));
+    }
+
+    #[test]
+    fn test_get_player_move_help() {
+        let mut test_game = Game::new()
+            .with_interrupt_save(Path::new("test.save"))
+            .with_fog_of_war(true);
+        let mut input = io::Cursor::new("help\n5\n");
+        let mut output = Vec::new();
+
+        assert_eq!(
+            test_game.get_player_move(&mut input, &mut output).unwrap(),
+            Some(5)
+        );
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert_eq!(output.matches("Please enter your move").count(), 2);
+        assert!(output.contains("help - Show this help."));
+        assert!(output.contains("saving the game to test.save"));
+        assert!(output.contains("3 in a row"));
+        assert!(output.contains("hidden as \"?\""));
+        assert!(!output.contains("wrap around"));
+        assert!(!output.contains("draw - "));
+        assert_eq!(test_game.history, Vec::<u32>::new());
+        assert_eq!(test_game.engine.current_turn, Turn::Player);
+    }
+
+    #[test]
+    fn test_play_rounds() {
+        let mut test_game = Game::from_notation("XX-OO----").unwrap();
+        let mut input = io::Cursor::new("3\ny\n");
+        let mut output = Vec::new();
+
+        test_game.play_rounds(&mut input, &mut output).unwrap();
+
+        assert!(String::from_utf8(output).unwrap().contains("You won!"));
+        assert_eq!(test_game.scoreboard.wins, 1);
+    }
+
+    #[test]
+    fn test_play_rounds_auto_continue() {
+        let mut test_game = Game::new()
+            .with_two_player(true)
+            .with_round_limit(3)
+            .with_auto_continue(true);
+        let moves = "1\n4\n2\n5\n3\n".repeat(3);
+        let mut input = io::Cursor::new(moves.as_str());
+        let mut output = Vec::new();
+
+        test_game.play_rounds(&mut input, &mut output).unwrap();
+
+        assert_eq!(input.position() as usize, moves.len());
+        assert!(!String::from_utf8(output)
+            .unwrap()
+            .contains("Are you finished"));
+        assert_eq!(
+            test_game.scoreboard,
+            Scoreboard {
+                wins: 1,
+                losses: 2,
+                draws: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_play_rounds_hold_result() {
+        let mut test_game = Game::from_notation("XX-OO----")
+            .unwrap()
+            .with_mark_last_move(true)
+            .with_hold_result(true);
+        let mut input = io::Cursor::new("3\n\ny\n");
+        let mut output = Vec::new();
+
+        test_game.play_rounds(&mut input, &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let board = output.find("| X | X |[X]|").unwrap();
+        let hold = output.find("Press enter to continue...").unwrap();
+        let finished = output.find("Are you finished").unwrap();
+
+        assert!(board < hold && hold < finished);
+        assert_eq!(test_game.scoreboard.wins, 1);
+    }
+
+    #[test]
+    fn test_play_rounds_persistent_score() {
+        let path = env::temp_dir().join("tic_tac_toe_test_play_rounds_persistent_score.score");
+        let _ = fs::remove_file(&path);
+        let mut test_game = Game::from_notation("XX-OO----")
+            .unwrap()
+            .with_persistent_score(&path);
+        let mut input = io::Cursor::new("3\ny\n");
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "0 0 0\n");
+
+        test_game.play_rounds(&mut input, &mut Vec::new()).unwrap();
+
+        assert_eq!(
+            Game::new().with_persistent_score(&path).scoreboard,
+            Scoreboard {
+                wins: 1,
+                losses: 0,
+                draws: 0,
+            }
+        );
+
+        fs::write(&path, "1 two 3\n").unwrap();
+
+        assert_eq!(
+            Game::new().with_persistent_score(&path).scoreboard,
+            Scoreboard::default()
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "0 0 0\n");
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_play_rounds_logfile() {
+        let path = env::temp_dir().join("tic_tac_toe_test_play_rounds_logfile.log");
+        let _ = fs::remove_file(&path);
+        let mut test_game = Game::new().with_two_player(true).with_logfile(&path);
+        let mut input = io::Cursor::new("1\n4\n2\n5\n3\nn\n1\n2\n4\n5\n7\ny\n");
+
+        test_game.play_rounds(&mut input, &mut Vec::new()).unwrap();
+
+        let log = fs::read_to_string(&path).unwrap();
+        let lines: Vec<Vec<&str>> = log.lines().map(|line| line.split(' ').collect()).collect();
+
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0][..2], ["XXXOO----", "win"]);
+        assert_eq!(lines[1][..2], ["OX-OX-O--", "loss"]);
+        assert!(lines
+            .iter()
+            .all(|line| line.len() == 3 && line[2].parse::<u64>().is_ok()));
+
+        let mut unwritable_game = Game::from_notation("XX-OO----").unwrap().with_logfile(
+            &env::temp_dir()
+                .join("tic_tac_toe_missing_dir")
+                .join("game.log"),
+        );
+
+        assert!(unwritable_game
+            .play_rounds(&mut io::Cursor::new("3\ny\n"), &mut Vec::new())
+            .is_err());
+    }
+
+    #[test]
+    fn test_play_rounds_event_sender() {
+        let (sender, receiver) = mpsc::channel();
+        let mut test_game = Game::from_notation("XX-OO----")
+            .unwrap()
+            .with_event_sender(sender);
+        let mut input = io::Cursor::new("3\ny\n");
+
+        test_game.play_rounds(&mut input, &mut Vec::new()).unwrap();
+
+        assert_eq!(
+            receiver.try_iter().collect::<Vec<GameEvent>>(),
+            vec![
+                GameEvent::MovePlayed(Turn::Player, 3),
+                GameEvent::RoundWon(Turn::Player),
+                GameEvent::ScoreUpdated(Scoreboard {
+                    wins: 1,
+                    losses: 0,
+                    draws: 0,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_play_rounds_draw_offer() {
+        let mut test_game = Game::new().with_two_player(true);
+        let mut input = io::Cursor::new("5\ndraw\nno\n1\ndraw\nmaybe\ny\ny\n");
+        let mut output = Vec::new();
+
+        test_game.play_rounds(&mut input, &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("O offers a draw. X, do you accept (y/n)?:"));
+        assert!(output.contains("The draw was declined."));
+        assert!(output.contains("X offers a draw. O, do you accept (y/n)?:"));
+        assert!(output.contains("Please input y or n!"));
+        assert!(output.contains("It's a draw!"));
+        assert_eq!(test_game.scoreboard.draws, 1);
+    }
+
+    #[test]
+    fn test_play_turn_undo_allowed() {
+        let mut test_game = Game::new().with_two_player(true);
+        let mut input = io::Cursor::new("undo\n5\nundo\ny\n9\n");
+        let mut output = Vec::new();
+
+        test_game.play_turn(&mut input, &mut output).unwrap();
+        test_game.engine.current_turn = Turn::Bot;
+        test_game.apply_move(1).unwrap();
+        test_game.play_turn(&mut input, &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("There is no move to undo."));
+        assert!(output.contains("X wants to undo their last move. O, allow undo? (y/n)"));
+        assert_eq!(test_game.to_notation(), "--------X");
+        assert_eq!(test_game.history, vec![9]);
+    }
+
+    #[test]
+    fn test_play_turn_undo_denied() {
+        let mut test_game = Game::new().with_two_player(true);
+        let mut input = io::Cursor::new("undo\nn\n9\n");
+        let mut output = Vec::new();
+
+        test_game.apply_move(5).unwrap();
+        test_game.apply_move(1).unwrap();
+        test_game.play_turn(&mut input, &mut output).unwrap();
+
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("The undo was denied."));
+        assert_eq!(test_game.to_notation(), "O---X---X");
+        assert_eq!(test_game.history, vec![5, 1, 9]);
+    }
+
+    #[test]
+    fn test_play_turn_confirm_moves() {
+        let mut test_game = Game::new().with_confirm_moves(true);
+        let mut input = io::Cursor::new("5\nn\n7\ny\n");
+        let mut output = Vec::new();
+
+        test_game.play_turn(&mut input, &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("Place X on 5. Confirm? (y/n)"));
+        assert!(output.contains("The move was cancelled."));
+        assert_eq!(test_game.to_notation(), "------X--");
+    }
+
+    #[test]
+    fn test_play_turn_token_rule() {
+        let rules = [
+            (TokenRule::FixedSide, "O", "X"),
+            (TokenRule::FirstMoverIsX, "X", "O"),
+        ];
+
+        for &(token_rule, bot_token, player_token) in &rules {
+            let mut test_game = Game::new()
+                .with_token_rule(token_rule)
+                .with_scripted_bot(vec![5]);
+            let mut input = io::Cursor::new("1\n");
+
+            test_game.set_turn(Turn::Bot);
+            test_game.play_turn(&mut input, &mut Vec::new()).unwrap();
+            test_game.set_turn(Turn::Player);
+            test_game.play_turn(&mut input, &mut Vec::new()).unwrap();
+
+            assert_eq!(test_game.engine.board[1][1], bot_token);
+            assert_eq!(test_game.engine.board[0][0], player_token);
         }
+    }
 
-        for bad_move in 10..20 {
-            assert!(
-                test_game
-                    .validate_player_input(&bad_move.to_string())
-                    .is_err()
+    #[test]
+    fn test_play_turn_mercy_rule() {
+        let mut lost_game = Game::from_notation("OO-OXX-X-")
+            .unwrap()
+            .with_mercy_rule(true);
+        let mut input = io::Cursor::new("resign\n");
+        let mut output = Vec::new();
+
+        assert_eq!(
+            lost_game.play_turn(&mut input, &mut output).unwrap(),
+            TurnEnd::Resigned
+        );
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("This position is lost \u{2014} resign or play on?"));
+
+        let mut drawable_game = Game::from_notation("X---O----")
+            .unwrap()
+            .with_mercy_rule(true);
+        let mut input = io::Cursor::new("3\n");
+        let mut output = Vec::new();
+
+        assert_eq!(
+            drawable_game.play_turn(&mut input, &mut output).unwrap(),
+            TurnEnd::Moved
+        );
+        assert!(!String::from_utf8(output)
+            .unwrap()
+            .contains("This position is lost"));
+    }
+
+    #[test]
+    fn test_play_turn_mercy_rule_declined() {
+        let mut test_game = Game::from_notation("-----OXXO")
+            .unwrap()
+            .with_mercy_rule(true)
+            .with_scripted_bot(vec![4]);
+        let mut input = io::Cursor::new("play on\n1\n2\n");
+        let mut output = Vec::new();
+
+        for _ in 0..3 {
+            assert_eq!(
+                test_game.play_turn(&mut input, &mut output).unwrap(),
+                TurnEnd::Moved
             );
+
+            test_game.engine.current_turn = test_game.engine.get_next_turn();
         }
+
+        assert_eq!(test_game.to_notation(), "XX-O-OXXO");
+        assert_eq!(
+            String::from_utf8(output)
+                .unwrap()
+                .matches("This position is lost")
+                .count(),
+            1
+        );
+
+        test_game.reset();
+
+        assert!(!test_game.mercy_declined);
     }
 
     #[test]
-    fn test_move_to_board_location() {
-        assert_eq!(Game::move_to_board_location(1), (0, 0));
-        assert_eq!(Game::move_to_board_location(2), (0, 1));
-        assert_eq!(Game::move_to_board_location(3), (0, 2));
+    fn test_play_turn_challenge() {
+        let mut test_game = Game::from_notation("XX-OO-X--")
+            .unwrap()
+            .with_challenge(true)
+            .with_scripted_bot(vec![6]);
+        let mut output = Vec::new();
 
-        assert_eq!(Game::move_to_board_location(4), (1, 0));
-        assert_eq!(Game::move_to_board_location(5), (1, 1));
-        assert_eq!(Game::move_to_board_location(6), (1, 2));
+        test_game.set_turn(Turn::Bot);
 
-        assert_eq!(Game::move_to_board_location(7), (2, 0));
-        assert_eq!(Game::move_to_board_location(8), (2, 1));
-        assert_eq!(Game::move_to_board_location(9), (2, 2));
+        assert_eq!(
+            test_game
+                .play_turn(&mut io::Cursor::new(""), &mut output)
+                .unwrap(),
+            TurnEnd::Moved
+        );
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("Bot played moved at: 6\nI'm winning!\n"));
     }
 
     #[test]
-    fn test_get_next_turn() {
+    fn test_play_turn_time_controls() {
+        let mut test_game =
+            Game::new().with_time_controls(Duration::new(0, 0), Duration::new(1, 0));
+        let mut input = io::Cursor::new("5\n");
+        let mut output = Vec::new();
+
+        assert_eq!(
+            test_game.play_turn(&mut input, &mut output).unwrap(),
+            TurnEnd::TimedOut
+        );
+        assert_eq!(test_game.to_notation(), "---------");
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("X has 0.0s to move."));
+    }
+
+    #[test]
+    fn test_resolve_forfeit() {
         let mut test_game = Game::new();
+        let mut output = Vec::new();
 
-        assert_eq!(test_game.get_next_turn(), Turn::Bot);
+        assert_eq!(
+            test_game
+                .resolve_forfeit(Turn::Player, &mut output)
+                .unwrap(),
+            "loss"
+        );
+        assert_eq!(test_game.scoreboard().losses, 1);
+        assert_eq!(test_game.scoreboard().wins, 0);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Time is up, you forfeit the round!\n"
+        );
 
-        test_game.current_turn = Turn::Bot;
+        assert_eq!(
+            test_game
+                .resolve_forfeit(Turn::Bot, &mut Vec::new())
+                .unwrap(),
+            "win"
+        );
+        assert_eq!(test_game.scoreboard().wins, 1);
+    }
 
-        assert_eq!(test_game.get_next_turn(), Turn::Player);
+    #[test]
+    fn test_parse_resign_answer() {
+        assert_eq!(Game::parse_resign_answer("Resign"), Ok(true));
+        assert_eq!(Game::parse_resign_answer(" play on "), Ok(false));
+        assert!(Game::parse_resign_answer("maybe").is_err());
     }
 
     #[test]
-    fn test_game_is_not_won() {
-        let test_game = Game::new();
+    fn test_apply_setup() {
+        let mut test_game = Game::new();
 
-        assert!(!test_game.game_is_won());
+        assert_eq!(
+            test_game.parse_placements("X:5 o:1 X:9"),
+            Ok(vec![(Turn::Player, 5), (Turn::Bot, 1), (Turn::Player, 9)])
+        );
+        assert!(test_game.parse_placements("X5").is_err());
+        assert!(test_game.parse_placements("Z:5").is_err());
+
+        assert!(test_game.apply_setup("X:5 X:1 O:9").is_err());
+        assert_eq!(test_game.to_notation(), "---------");
+        assert!(test_game.apply_setup("X:5 O:5").is_err());
+
+        test_game.apply_setup("X:5 O:1").unwrap();
+
+        assert_eq!(test_game.to_notation(), "O---X----");
+        assert_eq!(test_game.engine.current_turn, Turn::Player);
     }
 
     #[test]
-    fn test_game_is_won_row() {
+    #[cfg(debug_assertions)]
+    fn test_get_player_move_setup() {
         let mut test_game = Game::new();
+        let mut input = io::Cursor::new("setup X:5 O:9\n1\n");
+        let mut output = Vec::new();
+
+        assert_eq!(
+            test_game.get_player_move(&mut input, &mut output).unwrap(),
+            Some(1)
+        );
+        assert_eq!(test_game.to_notation(), "----X---O");
+    }
+
+    #[test]
+    fn test_get_player_move_try() {
+        let mut test_game = Game::from_notation("XX-OO----").unwrap();
+        let mut input = io::Cursor::new("try 7\ntry 6\nmaybe\nback\n3\n");
+        let mut output = Vec::new();
 
-        test_game.board[1][0] = String::from("O");
-        test_game.board[1][1] = String::from("O");
-        test_game.board[1][2] = String::from("O");
+        assert_eq!(
+            test_game.get_player_move(&mut input, &mut output).unwrap(),
+            Some(3)
+        );
 
-        assert!(test_game.game_is_won());
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("Evaluation: a win for O in 1 plies, advantage -1.00."));
+        assert!(output.contains("Evaluation: O has won."));
+        assert!(output.contains("Please input try <move> or back!"));
+        assert!(output.contains("Back to the real position."));
+        assert_eq!(test_game.to_notation(), "XX-OO----");
+        assert_eq!(test_game.history, Vec::<u32>::new());
+        assert_eq!(test_game.engine.current_turn, Turn::Player);
     }
 
     #[test]
-    fn test_game_is_won_col() {
-        let mut test_game = Game::new();
+    fn test_parse_branch_command() {
+        assert_eq!(Game::parse_branch_command("try 5"), Ok(Some(5)));
+        assert_eq!(Game::parse_branch_command(" Back "), Ok(None));
+        assert!(Game::parse_branch_command("try").is_err());
+        assert!(Game::parse_branch_command("try five").is_err());
+    }
 
-        test_game.board[0][2] = String::from("X");
-        test_game.board[1][2] = String::from("X");
-        test_game.board[2][2] = String::from("X");
+    #[test]
+    fn test_commands_hint() {
+        let mut test_game = Game::new()
+            .with_two_player(true)
+            .with_show_commands_hint(true);
+        let mut input = io::Cursor::new("5\n");
+        let mut output = Vec::new();
+
+        assert_eq!(Game::new().commands_hint(), "Commands: help, try");
 
-        assert!(test_game.game_is_won());
+        test_game.get_player_move(&mut input, &mut output).unwrap();
+
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("\"help\": \nCommands: help, try, draw, undo\n"));
     }
 
     #[test]
-    fn test_game_is_won_diag() {
-        let mut test_game = Game::new();
+    fn test_parse_yes_no() {
+        assert_eq!(Game::parse_yes_no("Y"), Ok(true));
+        assert_eq!(Game::parse_yes_no("yes"), Ok(true));
+        assert_eq!(Game::parse_yes_no(" n "), Ok(false));
+        assert_eq!(Game::parse_yes_no("No"), Ok(false));
+        assert!(Game::parse_yes_no("maybe").is_err());
+    }
+
+    #[test]
+    fn test_token_counts() {
+        let test_game = Game::from_notation("XO-XO-X--").unwrap();
+
+        assert_eq!(test_game.token_counts(), (3, 2));
+        assert_eq!(Game::new().token_counts(), (0, 0));
+    }
+
+    #[test]
+    fn test_validate_state() {
+        let mut test_game = Game::from_notation("XO-XO----").unwrap();
+
+        assert!(test_game.validate_state().is_ok());
+
+        test_game.set_turn(Turn::Bot);
+
+        assert!(test_game.validate_state().is_ok());
+
+        test_game.engine.board[2][2] = String::from("X");
+
+        assert!(test_game.validate_state().is_ok());
+
+        test_game.set_turn(Turn::Player);
+
+        assert!(test_game.validate_state().is_err());
+
+        test_game.engine.board[2][1] = String::from("X");
+        test_game.set_turn(Turn::Bot);
+
+        assert!(test_game.validate_state().is_err());
+    }
+
+    #[test]
+    fn test_configure_interactively() {
+        let mut input = io::Cursor::new("4\n3\nhard\nhigh-contrast\n");
+        let mut output = Vec::new();
+        let test_game = Game::configure_interactively(&mut input, &mut output).unwrap();
+
+        assert_eq!(test_game.engine.board.len(), 4);
+        assert_eq!(test_game.engine.win_length, 3);
+        assert_eq!(test_game.config.difficulty, Difficulty::Hard);
+        assert_eq!(test_game.config.theme, Theme::HighContrast);
+    }
+
+    #[test]
+    fn test_configure_interactively_reprompts() {
+        let mut input = io::Cursor::new("two\n2\n3\n4\n3\nimpossible\nMedium\nneon\nmonochrome\n");
+        let mut output = Vec::new();
+        let test_game = Game::configure_interactively(&mut input, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
 
-        test_game.board[0][2] = String::from("X");
-        test_game.board[1][1] = String::from("X");
-        test_game.board[2][0] = String::from("X");
+        assert_eq!(test_game.engine.board.len(), 3);
+        assert_eq!(test_game.engine.win_length, 3);
+        assert_eq!(test_game.config.difficulty, Difficulty::Medium);
+        assert_eq!(output.matches("enter the board size").count(), 3);
+        assert_eq!(output.matches("enter the win length").count(), 2);
+        assert_eq!(output.matches("enter the difficulty").count(), 2);
+        assert!(output.contains("Please input a valid unsigned integer!"));
+        assert!(output.contains("Please input easy, medium, or hard!"));
+        assert!(output.contains("Please input default, high-contrast, or monochrome!"));
+    }
+
+    #[test]
+    fn test_configure_interactively_eof() {
+        let mut input = io::Cursor::new("4\n");
+        let mut output = Vec::new();
+        let result = Game::configure_interactively(&mut input, &mut output);
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_difficulty_from_str() {
+        assert_eq!("easy".parse::<Difficulty>(), Ok(Difficulty::Easy));
+        assert_eq!("Medium".parse::<Difficulty>(), Ok(Difficulty::Medium));
+        assert_eq!("HARD".parse::<Difficulty>(), Ok(Difficulty::Hard));
+        assert!("expert".parse::<Difficulty>().is_err());
+    }
+
+    #[test]
+    fn test_with_size() {
+        let test_game = Game::new().with_size(4).unwrap();
+
+        assert_eq!(test_game.engine.board.len(), 4);
+        assert_eq!(test_game.engine.win_length, 4);
+        assert_eq!(test_game.available_moves().len(), 16);
+        assert_eq!(test_game.engine.move_to_board_location(16), (3, 3));
+        assert_eq!(test_game.engine.board[3][3], "16");
+        assert!(Game::new().with_size(2).is_err());
+    }
+
+    #[test]
+    fn test_with_size_too_large() {
+        assert!(Game::new().with_size(usize::MAX).is_err());
+        assert!(Game::new().with_size(100_000).is_err());
+        assert!(Game::new().with_size(MAX_SIZE + 1).is_err());
+        assert!(Game::new().with_size(MAX_SIZE).is_ok());
+    }
+
+    #[test]
+    fn test_with_win_length() {
+        let test_game = Game::new().with_size(5).unwrap();
+
+        assert!(test_game.clone().with_win_length(2).is_err());
+        assert!(test_game.clone().with_win_length(6).is_err());
+        assert_eq!(test_game.with_win_length(4).unwrap().engine.win_length, 4);
+    }
+
+    #[test]
+    fn test_game_is_won_win_length() {
+        let mut test_game = Game::new()
+            .with_size(4)
+            .unwrap()
+            .with_win_length(3)
+            .unwrap();
+
+        test_game.apply_move(6).unwrap();
+        test_game.apply_move(1).unwrap();
+        test_game.apply_move(11).unwrap();
+        test_game.apply_move(2).unwrap();
+
+        assert!(!test_game.engine.game_is_won());
+
+        test_game.apply_move(16).unwrap();
+
+        assert!(test_game.engine.game_is_won());
+    }
+
+    #[test]
+    fn test_get_bot_move_medium_blocks() {
+        let mut test_game = Game::from_notation("XX-O-----")
+            .unwrap()
+            .with_difficulty(Difficulty::Medium);
+
+        assert_eq!(test_game.get_bot_move(&mut io::sink()).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_get_bot_move_medium_wins() {
+        let mut test_game = Game::from_notation("XX-OO-X--")
+            .unwrap()
+            .with_difficulty(Difficulty::Medium);
 
-        assert!(test_game.game_is_won());
+        assert_eq!(test_game.get_bot_move(&mut io::sink()).unwrap(), 6);
     }
 }
@@ -0,0 +1,10 @@
+//! A Rustaceans Version of Tic-Tac-Toe!
+//!
+//! The game logic lives in the `game` module and the terminal handling in the `terminal` module,
+//! while the binary only sets up and starts a game.
+#[cfg(unix)]
+extern crate libc;
+extern crate rand;
+
+pub mod game;
+pub mod terminal;
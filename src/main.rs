@@ -1,13 +1,57 @@
-extern crate rand;
+extern crate tic_tac_toe;
 
-mod game;
-
-use game::Game;
+use std::env;
+use std::io;
+use std::path::Path;
+use std::process;
+use tic_tac_toe::game;
+use tic_tac_toe::game::{Game, Outcome, Turn};
+use tic_tac_toe::terminal;
 
 fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    if args.len() == 3 && args[1] == "--moves" {
+        play_moves_file(Path::new(&args[2]));
+    }
+
     println!("Welcome to Tic-Tac-Toe!");
 
-    let mut game = Game::new();
+    terminal::install_interrupt_handler();
+
+    let save_path = Path::new("tic_tac_toe.save");
+    let game = if save_path.exists() {
+        Game::resume_or_new(save_path)
+    } else {
+        match Game::configure_interactively(&mut terminal::Stdin::new(), &mut io::stdout()) {
+            Ok(game) => game,
+            Err(err) => {
+                println!("\nCould not configure the game: {}", err);
+                return;
+            }
+        }
+    };
+    let mut game = game.with_interrupt_save(save_path);
 
     game.play_game();
 }
+
+/// Plays the moves of a file non-interactively, then exits with the code of the result, or 3 if
+/// the moves could not be played or don't finish the game.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file of moves, one move per line.
+fn play_moves_file(path: &Path) -> ! {
+    let outcome = match Game::new().play_from_file(path, &mut io::stdout()) {
+        Ok(Some(Turn::Player)) => Outcome::Win,
+        Ok(Some(Turn::Bot)) => Outcome::Loss,
+        Ok(None) => Outcome::Draw,
+        Err(err) => {
+            eprintln!("Could not play the moves: {}", err);
+            process::exit(3);
+        }
+    };
+
+    process::exit(game::exit_code(outcome))
+}
@@ -0,0 +1,607 @@
+//! This a module for interacting with the terminal.
+//!
+//! It contains the handling of interrupts (Ctrl-C), the reading of input lines from stdin, and a
+//! struct for the history of input lines. The `Input` trait abstracts over sources of input lines,
+//! so e.g. prompts can read from a script in tests. An interrupt stops a pending read with an
+//! `Interrupted` error, instead of killing the process, so the game can wrap up the session
+//! first.
+#[cfg(unix)]
+use libc;
+use std::io;
+#[cfg(unix)]
+use std::io::Write;
+use std::io::{BufRead, Read};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The longest input line read, in bytes, so pasting megabytes of text cannot exhaust memory.
+pub const MAX_LINE_LENGTH: usize = 1024;
+
+/// A source of input lines.
+pub trait Input {
+    /// Reads a line, without the line ending.
+    ///
+    /// Reaching the end of the input is an `UnexpectedEof` error, a line longer than
+    /// `MAX_LINE_LENGTH` is an `InvalidInput` error, and a line that is not valid UTF-8 is an
+    /// `InvalidData` error. The rest of the line is skipped on an error, so the next line can
+    /// still be read.
+    fn read_line(&mut self) -> io::Result<String>;
+}
+
+impl<R: BufRead> Input for R {
+    /// Reads a line from the buffered reader, without the line ending.
+    fn read_line(&mut self) -> io::Result<String> {
+        let mut line = Vec::new();
+
+        let mut handle = Read::take(&mut *self, MAX_LINE_LENGTH as u64 + 1);
+
+        if handle.read_until(b'\n', &mut line)? == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "No more input!",
+            ));
+        }
+
+        if line.len() > MAX_LINE_LENGTH && line.last() != Some(&b'\n') {
+            skip_line(self)?;
+
+            return Err(line_too_long());
+        }
+
+        String::from_utf8(line)
+            .map(|line| line.trim_end_matches(&['\r', '\n'][..]).to_string())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Input is not valid UTF-8!"))
+    }
+}
+
+/// Skips the rest of a line of a buffered reader, without keeping it in memory.
+///
+/// # Arguments
+///
+/// * `reader` - The buffered reader.
+fn skip_line<R: BufRead>(reader: &mut R) -> io::Result<()> {
+    loop {
+        let (skipped, found_newline) = {
+            let buffer = reader.fill_buf()?;
+
+            match buffer.iter().position(|&byte| byte == b'\n') {
+                Some(index) => (index + 1, true),
+                None => (buffer.len(), buffer.is_empty()),
+            }
+        };
+
+        reader.consume(skipped);
+
+        if found_newline {
+            return Ok(());
+        }
+    }
+}
+
+/// Gets the error of an input line longer than `MAX_LINE_LENGTH`.
+fn line_too_long() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "Input is too long!")
+}
+
+/// The stdin of the terminal as a struct, read with `read_line_with_history`.
+#[derive(Clone, Debug, Default)]
+pub struct Stdin {
+    /// The history of the lines read, for recalling previous inputs.
+    history: InputHistory,
+}
+
+impl Stdin {
+    /// Constructs a `Stdin` object with an empty history.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::terminal::Stdin;
+    ///
+    /// let stdin = Stdin::new();
+    /// ```
+    pub fn new() -> Stdin {
+        Stdin::default()
+    }
+}
+
+impl Input for Stdin {
+    /// Reads a line from stdin with `read_line_with_history`.
+    fn read_line(&mut self) -> io::Result<String> {
+        read_line_with_history(&mut self.history)
+    }
+}
+
+/// The history of input lines as a struct, for recalling previous inputs.
+///
+/// While recalling, the position moves back through older entries with `recall_prev` and
+/// forward through newer entries with `recall_next`. Pushing a new entry ends the recall.
+#[derive(Clone, Debug, Default)]
+pub struct InputHistory {
+    /// The input lines, from oldest to newest.
+    entries: Vec<String>,
+    /// The position of the recalled entry, or the number of entries when not recalling.
+    position: usize,
+}
+
+impl InputHistory {
+    /// Constructs an empty `InputHistory` object.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::terminal::InputHistory;
+    ///
+    /// let history = InputHistory::new();
+    /// ```
+    pub fn new() -> InputHistory {
+        InputHistory::default()
+    }
+
+    /// Pushes an input line as the newest entry, ending any recall. Empty lines are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - The input line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::terminal::InputHistory;
+    ///
+    /// let mut history = InputHistory::new();
+    ///
+    /// history.push("5");
+    ///
+    /// assert_eq!(history.recall_prev(), Some("5"));
+    /// ```
+    pub fn push(&mut self, entry: &str) {
+        if !entry.trim().is_empty() {
+            self.entries.push(entry.to_string());
+        }
+
+        self.position = self.entries.len();
+    }
+
+    /// Recalls the entry before the current position, staying at the oldest entry once reached.
+    ///
+    /// Returns `None` if the history is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::terminal::InputHistory;
+    ///
+    /// let mut history = InputHistory::new();
+    ///
+    /// history.push("1");
+    /// history.push("2");
+    ///
+    /// assert_eq!(history.recall_prev(), Some("2"));
+    /// assert_eq!(history.recall_prev(), Some("1"));
+    /// assert_eq!(history.recall_prev(), Some("1"));
+    /// ```
+    pub fn recall_prev(&mut self) -> Option<&str> {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        self.position = self.position.saturating_sub(1);
+
+        Some(&self.entries[self.position])
+    }
+
+    /// Recalls the entry after the current position.
+    ///
+    /// Returns `None` once past the newest entry, which ends the recall.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tic_tac_toe::terminal::InputHistory;
+    ///
+    /// let mut history = InputHistory::new();
+    ///
+    /// history.push("1");
+    /// history.push("2");
+    /// history.recall_prev();
+    /// history.recall_prev();
+    ///
+    /// assert_eq!(history.recall_next(), Some("2"));
+    /// assert_eq!(history.recall_next(), None);
+    /// ```
+    pub fn recall_next(&mut self) -> Option<&str> {
+        if self.position + 1 < self.entries.len() {
+            self.position += 1;
+
+            Some(&self.entries[self.position])
+        } else {
+            self.position = self.entries.len();
+
+            None
+        }
+    }
+}
+
+/// The raw mode of the terminal as a struct, restoring the original mode when dropped.
+#[cfg(unix)]
+struct RawMode {
+    /// The original terminal attributes.
+    original: libc::termios,
+}
+
+#[cfg(unix)]
+impl RawMode {
+    /// Switches the terminal to raw mode, where input is read key by key without echoing.
+    ///
+    /// Interrupts (Ctrl-C) are still delivered as signals.
+    fn enable() -> io::Result<RawMode> {
+        unsafe {
+            let mut original: libc::termios = ::std::mem::zeroed();
+
+            if libc::tcgetattr(libc::STDIN_FILENO, &mut original) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            let mut raw = original;
+
+            raw.c_lflag &= !(libc::ICANON | libc::ECHO);
+            raw.c_cc[libc::VMIN] = 1;
+            raw.c_cc[libc::VTIME] = 0;
+
+            if libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &raw) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            Ok(RawMode { original })
+        }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        unsafe {
+            libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &self.original);
+        }
+    }
+}
+
+/// Whether an interrupt has been received.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Records that an interrupt has been received.
+///
+/// # Arguments
+///
+/// * `_signal` - The received signal.
+#[cfg(unix)]
+extern "C" fn handle_interrupt(_signal: libc::c_int) {
+    INTERRUPTED.store(true, Ordering::SeqCst);
+}
+
+/// Installs the interrupt (Ctrl-C) handler.
+///
+/// Once installed, an interrupt no longer kills the process, but makes `read_line` return an
+/// `Interrupted` error.
+///
+/// # Example
+///
+/// ```no_run
+/// use tic_tac_toe::terminal;
+///
+/// terminal::install_interrupt_handler();
+/// ```
+pub fn install_interrupt_handler() {
+    #[cfg(unix)]
+    unsafe {
+        let mut action: libc::sigaction = ::std::mem::zeroed();
+
+        // Without SA_RESTART, a pending read fails with EINTR once the handler returns.
+        action.sa_sigaction = handle_interrupt as extern "C" fn(libc::c_int) as libc::sighandler_t;
+        libc::sigemptyset(&mut action.sa_mask);
+        libc::sigaction(libc::SIGINT, &action, ::std::ptr::null_mut());
+    }
+}
+
+/// Determines if an interrupt has been received.
+pub fn was_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Reads a line from stdin, without the trailing newline.
+///
+/// Returns an `Interrupted` error if an interrupt has been received, an `UnexpectedEof` error if
+/// stdin is closed, an `InvalidInput` error if the line is longer than `MAX_LINE_LENGTH`, and an
+/// `InvalidData` error if the line is not valid UTF-8.
+pub fn read_line() -> io::Result<String> {
+    let mut line = Vec::new();
+
+    loop {
+        if was_interrupted() {
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "Interrupted!"));
+        }
+
+        match read_byte() {
+            Err(ref err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+            Ok(None) if line.is_empty() => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "No more input!",
+                ))
+            }
+            Ok(Some(b'\n')) | Ok(None) => break,
+            Ok(Some(_)) if line.len() > MAX_LINE_LENGTH => continue,
+            Ok(Some(byte)) => line.push(byte),
+        }
+    }
+
+    if line.len() > MAX_LINE_LENGTH {
+        return Err(line_too_long());
+    }
+
+    String::from_utf8(line)
+        .map(|line| line.trim_end_matches('\r').to_string())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Input is not valid UTF-8!"))
+}
+
+/// Reads a line from stdin like `read_line`, pushing it to the history.
+///
+/// If stdin is a terminal, the line is read key by key in raw mode, so the up and down arrows
+/// recall previous lines from the history.
+///
+/// # Arguments
+///
+/// * `history` - The history of input lines.
+pub fn read_line_with_history(history: &mut InputHistory) -> io::Result<String> {
+    let line = if stdin_is_terminal() {
+        read_line_raw(history)?
+    } else {
+        read_line()?
+    };
+
+    history.push(&line);
+
+    Ok(line)
+}
+
+/// Determines if stdin is a terminal.
+#[cfg(unix)]
+fn stdin_is_terminal() -> bool {
+    unsafe { libc::isatty(libc::STDIN_FILENO) == 1 }
+}
+
+/// Determines if stdin is a terminal.
+#[cfg(not(unix))]
+fn stdin_is_terminal() -> bool {
+    false
+}
+
+/// Reads a line from stdin in raw mode, echoing the keys and handling the history arrows.
+///
+/// # Arguments
+///
+/// * `history` - The history of input lines.
+#[cfg(unix)]
+fn read_line_raw(history: &mut InputHistory) -> io::Result<String> {
+    let _raw_mode = RawMode::enable()?;
+    let mut stdout = io::stdout();
+    let mut line: Vec<u8> = Vec::new();
+    let mut escape: Vec<u8> = Vec::new();
+
+    loop {
+        stdout.flush()?;
+
+        if was_interrupted() {
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "Interrupted!"));
+        }
+
+        let byte = match read_byte() {
+            Err(ref err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+            Ok(None) if line.is_empty() => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "No more input!",
+                ))
+            }
+            Ok(None) => break,
+            Ok(Some(byte)) => byte,
+        };
+
+        if !escape.is_empty() || byte == 0x1b {
+            escape.push(byte);
+
+            if !escape_is_complete(&escape) {
+                continue;
+            }
+
+            // Only the history arrows are handled, the other keys are ignored.
+            let recalled = match escape.as_slice() {
+                [0x1b, b'[', b'A'] => history.recall_prev().map(String::from),
+                [0x1b, b'[', b'B'] => Some(history.recall_next().unwrap_or("").to_string()),
+                _ => None,
+            };
+
+            escape.clear();
+
+            if let Some(recalled) = recalled {
+                line = recalled.into_bytes();
+
+                write!(stdout, "\r\x1b[K")?;
+                stdout.write_all(&line)?;
+            }
+
+            continue;
+        }
+
+        match byte {
+            b'\n' | b'\r' => {
+                writeln!(stdout)?;
+                break;
+            }
+            // Ctrl-D on an empty line closes the input, as in canonical mode.
+            4 if line.is_empty() => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "No more input!",
+                ))
+            }
+            8 | 127 => {
+                // Removes a whole UTF-8 character, including its continuation bytes.
+                while let Some(removed) = line.pop() {
+                    if removed & 0xc0 != 0x80 {
+                        write!(stdout, "\x08 \x08")?;
+                        break;
+                    }
+                }
+            }
+            // Keys past the longest line are dropped, like a full input field.
+            byte if byte >= 0x20 && line.len() < MAX_LINE_LENGTH => {
+                line.push(byte);
+                stdout.write_all(&[byte])?;
+            }
+            _ => (),
+        }
+    }
+
+    String::from_utf8(line)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Input is not valid UTF-8!"))
+}
+
+/// Determines if an escape sequence read in raw mode is complete, so none of its bytes end up
+/// in the line.
+///
+/// A control sequence, "ESC [", ends with its final byte, between 0x40 and 0x7e, e.g. the "A"
+/// of "ESC [ 1 ; 5 A" for Ctrl-Up. "ESC O" takes one more byte, and any other sequence ends with
+/// the byte after "ESC".
+///
+/// # Arguments
+///
+/// * `escape` - The bytes of the sequence read so far, starting with ESC.
+#[cfg(unix)]
+fn escape_is_complete(escape: &[u8]) -> bool {
+    match escape {
+        [0x1b] | [0x1b, b'['] | [0x1b, b'O'] => false,
+        [0x1b, b'[', .., last] => !(0x20..0x40).contains(last),
+        _ => true,
+    }
+}
+
+/// Reads a line from stdin, since raw mode is only supported on Unix.
+///
+/// # Arguments
+///
+/// * `_history` - The history of input lines.
+#[cfg(not(unix))]
+fn read_line_raw(_history: &mut InputHistory) -> io::Result<String> {
+    read_line()
+}
+
+/// Reads a single byte from stdin, or `None` if stdin is closed.
+///
+/// The read is unbuffered, so no input is held back from later reads.
+#[cfg(unix)]
+fn read_byte() -> io::Result<Option<u8>> {
+    let mut byte = 0u8;
+    let read = unsafe { libc::read(libc::STDIN_FILENO, &mut byte as *mut u8 as *mut _, 1) };
+
+    match read {
+        -1 => Err(io::Error::last_os_error()),
+        0 => Ok(None),
+        _ => Ok(Some(byte)),
+    }
+}
+
+/// Reads a single byte from stdin, or `None` if stdin is closed.
+#[cfg(not(unix))]
+fn read_byte() -> io::Result<Option<u8>> {
+    use std::io::Read;
+
+    let mut byte = [0u8];
+
+    match io::stdin().read(&mut byte)? {
+        0 => Ok(None),
+        _ => Ok(Some(byte[0])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_line_too_long() {
+        let long_line = "9".repeat(MAX_LINE_LENGTH + 1);
+        let mut input = io::Cursor::new(format!("{}\n{}\n5\n", long_line, &long_line[1..]));
+
+        assert_eq!(
+            Input::read_line(&mut input).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+        assert_eq!(Input::read_line(&mut input).unwrap(), &long_line[1..]);
+        assert_eq!(Input::read_line(&mut input).unwrap(), "5");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_escape_is_complete() {
+        assert!(!escape_is_complete(b"\x1b"));
+        assert!(!escape_is_complete(b"\x1b["));
+        assert!(escape_is_complete(b"\x1b[A"));
+        assert!(!escape_is_complete(b"\x1b[1;5"));
+        assert!(escape_is_complete(b"\x1b[1;5A"));
+        assert!(!escape_is_complete(b"\x1b[1"));
+        assert!(escape_is_complete(b"\x1b[1~"));
+        assert!(!escape_is_complete(b"\x1bO"));
+        assert!(escape_is_complete(b"\x1bOH"));
+        assert!(escape_is_complete(b"\x1bx"));
+    }
+
+    #[test]
+    fn test_read_line_invalid_utf8() {
+        let mut input = io::Cursor::new(b"\xff\xfe\n5\n".to_vec());
+
+        assert_eq!(
+            Input::read_line(&mut input).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(Input::read_line(&mut input).unwrap(), "5");
+    }
+
+    #[test]
+    fn test_input_history_recall() {
+        let mut history = InputHistory::new();
+
+        assert_eq!(history.recall_prev(), None);
+        assert_eq!(history.recall_next(), None);
+
+        history.push("1");
+        history.push("");
+        history.push("5");
+        history.push("9");
+
+        assert_eq!(history.recall_prev(), Some("9"));
+        assert_eq!(history.recall_prev(), Some("5"));
+        assert_eq!(history.recall_prev(), Some("1"));
+        assert_eq!(history.recall_prev(), Some("1"));
+        assert_eq!(history.recall_next(), Some("5"));
+        assert_eq!(history.recall_next(), Some("9"));
+        assert_eq!(history.recall_next(), None);
+        assert_eq!(history.recall_next(), None);
+        assert_eq!(history.recall_prev(), Some("9"));
+    }
+
+    #[test]
+    fn test_input_history_push_ends_recall() {
+        let mut history = InputHistory::new();
+
+        history.push("1");
+        history.push("2");
+        history.recall_prev();
+        history.recall_prev();
+        history.push("3");
+
+        assert_eq!(history.recall_prev(), Some("3"));
+    }
+}